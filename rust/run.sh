@@ -6,20 +6,17 @@ if [ -z "$DAY" ]; then
   exit 1
 fi
 
-FEATURES=""
+COMMAND="run"
+OPTIONS=""
 INPUT=""
-if [ "$2" == "timeit" ]; then
-  FEATURES="$2"
+if [ "$2" == "bench" ]; then
+  COMMAND="bench"
   INPUT="${@:3}"
-elif [ "$2" == "print" ]; then
-  FEATURES="$2"
+elif [ "$2" == "viz" ]; then
+  OPTIONS="--visualize"
   INPUT="${@:3}"
 else
   INPUT="${@:2}"
 fi
 
-if [ -z "$INPUT" ]; then
-  INPUT="../input/day$DAY"
-fi
-
-RUST_BACKTRACE=1 cargo run --features="$FEATURES" --bin "day$DAY" --release "$INPUT"
+RUST_BACKTRACE=1 cargo run --bin aoc --release -- $COMMAND "$DAY" $INPUT $OPTIONS
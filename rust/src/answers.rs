@@ -0,0 +1,146 @@
+//! Known correct answers for puzzle inputs, keyed by day, part and input hash, so solutions can
+//! be verified against real inputs and not only the examples.
+
+use std::fmt;
+use std::fs::{self, OpenOptions};
+use std::io::prelude::*;
+use std::path::Path;
+
+use anyhow::{Context, Result};
+
+pub const DEFAULT_PATH: &str = "../input/answers.csv";
+
+const HEADER: &str = "day,part,input_hash,answer";
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Expected {
+    pub day: u8,
+    pub part: u8,
+    pub input_hash: String,
+    pub answer: String,
+}
+
+impl Expected {
+    fn parse(line: &str) -> Result<Self> {
+        let mut fields = line.splitn(4, ',');
+        let mut next = |name: &str| fields.next().with_context(|| format!("No {name}"));
+
+        Ok(Self {
+            day: next("day")?.parse().context("Invalid day")?,
+            part: next("part")?.parse().context("Invalid part")?,
+            input_hash: next("input hash")?.to_string(),
+            answer: next("answer")?.to_string(),
+        })
+    }
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct Registry {
+    entries: Vec<Expected>,
+}
+
+impl Registry {
+    /// Loads the answers at `path`, where a missing file means no answers are known yet.
+    pub fn load(path: &Path) -> Result<Self> {
+        if !path.exists() {
+            return Ok(Self::default());
+        }
+        let content = fs::read_to_string(path)
+            .with_context(|| format!("Unable to read {}", path.display()))?;
+        content
+            .parse()
+            .with_context(|| format!("Invalid answers in {}", path.display()))
+    }
+
+    pub fn get(&self, day: u8, part: u8, input_hash: &str) -> Option<&str> {
+        self.entries
+            .iter()
+            .find(|e| e.day == day && e.part == part && e.input_hash == input_hash)
+            .map(|e| e.answer.as_str())
+    }
+
+    pub fn check(&self, day: u8, part: u8, input_hash: &str, answer: &str) -> Status {
+        match self.get(day, part, input_hash) {
+            Some(expected) if expected == answer => Status::Pass,
+            Some(expected) => Status::Fail {
+                expected: expected.to_string(),
+            },
+            None => Status::Missing,
+        }
+    }
+
+    /// Appends `expected` to the file at `path`, creating it with a header if needed.
+    pub fn append(path: &Path, expected: &[Expected]) -> Result<()> {
+        let has_header = path.metadata().is_ok_and(|m| m.len() > 0);
+        let mut file = OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(path)
+            .with_context(|| format!("Unable to open {}", path.display()))?;
+
+        if !has_header {
+            writeln!(file, "{HEADER}")?;
+        }
+        for e in expected {
+            writeln!(file, "{},{},{},{}", e.day, e.part, e.input_hash, e.answer)?;
+        }
+        Ok(())
+    }
+}
+
+impl std::str::FromStr for Registry {
+    type Err = anyhow::Error;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let entries = s
+            .lines()
+            .enumerate()
+            .filter(|(_, line)| !line.is_empty() && *line != HEADER)
+            .map(|(i, line)| Expected::parse(line).with_context(|| format!("On line {}", i + 1)))
+            .collect::<Result<_>>()?;
+        Ok(Self { entries })
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Status {
+    Pass,
+    Fail { expected: String },
+    Missing,
+}
+
+impl fmt::Display for Status {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Status::Pass => write!(f, "PASS"),
+            Status::Fail { .. } => write!(f, "FAIL"),
+            Status::Missing => write!(f, "MISSING"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const ANSWERS: &str = "
+day,part,input_hash,answer
+5,1,abc,35
+5,2,abc,46
+5,1,def,107430936";
+
+    #[test]
+    fn test_check() -> Result<()> {
+        let answers = ANSWERS.parse::<Registry>()?;
+        assert_eq!(answers.check(5, 1, "abc", "35"), Status::Pass);
+        assert_eq!(
+            answers.check(5, 2, "abc", "47"),
+            Status::Fail {
+                expected: "46".to_string()
+            }
+        );
+        assert_eq!(answers.check(5, 2, "def", "46"), Status::Missing);
+        assert_eq!(answers.check(6, 1, "abc", "35"), Status::Missing);
+        assert!("5,1".parse::<Registry>().is_err());
+        Ok(())
+    }
+}
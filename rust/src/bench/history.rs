@@ -0,0 +1,291 @@
+//! A local history of benchmark runs, stored as the CSV produced by `Results::to_csv`, and
+//! comparison of recorded runs to find regressions.
+
+use std::fmt;
+use std::fs::{self, OpenOptions};
+use std::io::prelude::*;
+use std::path::Path;
+use std::time::Duration;
+
+use anyhow::{bail, Context, Result};
+
+use super::results::CSV_HEADER;
+use super::{Results, Stats};
+use crate::as_ms;
+
+pub const DEFAULT_PATH: &str = "bench_history.csv";
+
+/// One recorded row: the timings of one phase of one day in one run.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Entry {
+    pub timestamp: u64,
+    pub commit: String,
+    pub cpu: String,
+    pub day: u8,
+    pub input_hash: String,
+    pub phase: String,
+    pub stats: Stats,
+}
+
+impl Entry {
+    fn parse(line: &str) -> Result<Self> {
+        let fields = split_csv(line);
+        if fields.len() != 13 {
+            bail!("Expected 13 fields but found {}", fields.len());
+        }
+        let nanos = |i: usize| -> Result<Duration> {
+            Ok(Duration::from_nanos(
+                fields[i].parse().context("Invalid duration")?,
+            ))
+        };
+
+        Ok(Self {
+            timestamp: fields[0].parse().context("Invalid timestamp")?,
+            commit: fields[1].clone(),
+            cpu: fields[2].clone(),
+            day: fields[3].parse().context("Invalid day")?,
+            input_hash: fields[4].clone(),
+            phase: fields[5].clone(),
+            stats: Stats {
+                samples: fields[6].parse().context("Invalid sample count")?,
+                median: nanos(7)?,
+                mean: nanos(8)?,
+                min: nanos(9)?,
+                max: nanos(10)?,
+                stddev: nanos(11)?,
+                outliers: fields[12].parse().context("Invalid outlier count")?,
+            },
+        })
+    }
+
+    fn key(&self) -> (u8, &str, &str) {
+        (self.day, &self.input_hash, &self.phase)
+    }
+}
+
+fn split_csv(line: &str) -> Vec<String> {
+    let mut fields = vec![];
+    let mut field = String::new();
+    let mut quoted = false;
+    let mut chars = line.chars().peekable();
+
+    while let Some(c) = chars.next() {
+        match c {
+            '"' if quoted && chars.peek() == Some(&'"') => {
+                chars.next();
+                field.push('"');
+            }
+            '"' => quoted = !quoted,
+            ',' if !quoted => fields.push(std::mem::take(&mut field)),
+            c => field.push(c),
+        }
+    }
+    fields.push(field);
+    fields
+}
+
+/// Appends `results` to the history at `path`, creating it with a header if needed.
+pub fn append(path: &Path, results: &Results) -> Result<()> {
+    let has_header = path.metadata().is_ok_and(|m| m.len() > 0);
+    let mut file = OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(path)
+        .with_context(|| format!("Unable to open {}", path.display()))?;
+
+    if !has_header {
+        writeln!(file, "{CSV_HEADER}")?;
+    }
+    file.write_all(results.csv_rows().as_bytes())?;
+    Ok(())
+}
+
+pub fn load(path: &Path) -> Result<Vec<Entry>> {
+    let content =
+        fs::read_to_string(path).with_context(|| format!("Unable to read {}", path.display()))?;
+    parse(&content).with_context(|| format!("Invalid history in {}", path.display()))
+}
+
+fn parse(content: &str) -> Result<Vec<Entry>> {
+    content
+        .lines()
+        .enumerate()
+        .filter(|(_, line)| !line.is_empty() && *line != CSV_HEADER)
+        .map(|(i, line)| Entry::parse(line).with_context(|| format!("On line {}", i + 1)))
+        .collect()
+}
+
+/// The change in median time for one phase of one day between two recorded commits.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Change {
+    pub day: u8,
+    pub phase: String,
+    pub baseline: Duration,
+    pub current: Duration,
+}
+
+impl Change {
+    /// Relative change in percent, positive when it got slower.
+    pub fn percent(&self) -> f64 {
+        let baseline = self.baseline.as_nanos().max(1) as f64;
+        (self.current.as_nanos() as f64 - baseline) / baseline * 100.0
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Comparison {
+    pub baseline: String,
+    pub current: String,
+    /// How many percent slower a phase may get before it is flagged.
+    pub threshold: f64,
+    pub changes: Vec<Change>,
+}
+
+impl Comparison {
+    pub fn regressions(&self) -> impl Iterator<Item = &Change> {
+        self.changes.iter().filter(|c| c.percent() > self.threshold)
+    }
+}
+
+impl fmt::Display for Comparison {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(
+            f,
+            "Comparing {} to baseline {} (threshold {}%)",
+            self.current, self.baseline, self.threshold
+        )?;
+        for change in &self.changes {
+            let flag = if change.percent() > self.threshold {
+                "  SLOWER"
+            } else {
+                ""
+            };
+            writeln!(
+                f,
+                "Day {:02} {:<5} {:>12.4}ms -> {:>12.4}ms {:>+8.1}%{flag}",
+                change.day,
+                change.phase,
+                as_ms(change.baseline),
+                as_ms(change.current),
+                change.percent()
+            )?;
+        }
+        Ok(())
+    }
+}
+
+/// The latest entry of `commit` for each day, input and phase, in recorded order.
+fn latest<'a>(entries: &'a [Entry], commit: &str) -> Vec<&'a Entry> {
+    let mut res: Vec<&Entry> = vec![];
+    for entry in entries.iter().filter(|e| e.commit == commit) {
+        match res.iter_mut().find(|e| e.key() == entry.key()) {
+            Some(e) => *e = entry,
+            None => res.push(entry),
+        }
+    }
+    res
+}
+
+fn resolve<'a>(entries: &'a [Entry], commit: &str) -> Result<&'a str> {
+    entries
+        .iter()
+        .rev()
+        .find(|e| e.commit.starts_with(commit))
+        .map(|e| e.commit.as_str())
+        .with_context(|| format!("No recorded benchmarks for commit {commit}"))
+}
+
+/// Compares two recorded commits, by default the latest recorded commit against the one
+/// recorded before it. Commits may be given as a prefix of the recorded hash.
+pub fn compare(
+    entries: &[Entry],
+    baseline: Option<&str>,
+    current: Option<&str>,
+    threshold: f64,
+) -> Result<Comparison> {
+    let current = match current {
+        Some(commit) => resolve(entries, commit)?,
+        None => entries
+            .last()
+            .map(|e| e.commit.as_str())
+            .context("No recorded benchmarks")?,
+    };
+    let baseline = match baseline {
+        Some(commit) => resolve(entries, commit)?,
+        None => entries
+            .iter()
+            .rev()
+            .find(|e| e.commit != current)
+            .map(|e| e.commit.as_str())
+            .context("No earlier commit recorded to compare with")?,
+    };
+
+    let baseline_entries = latest(entries, baseline);
+    let changes = latest(entries, current)
+        .into_iter()
+        .filter_map(|cur| {
+            let base = baseline_entries.iter().find(|b| b.key() == cur.key())?;
+            Some(Change {
+                day: cur.day,
+                phase: cur.phase.clone(),
+                baseline: base.stats.median,
+                current: cur.stats.median,
+            })
+        })
+        .collect();
+
+    Ok(Comparison {
+        baseline: baseline.to_string(),
+        current: current.to_string(),
+        threshold,
+        changes,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const HISTORY: &str = "\
+timestamp,commit,cpu,day,input_hash,phase,samples,median_ns,mean_ns,min_ns,max_ns,stddev_ns,outliers
+1,aaa,\"CPU, fast\",11,h11,total,10,1000,1000,1000,1000,0,0
+1,aaa,\"CPU, fast\",11,h11,part1,10,400,400,400,400,0,0
+1,aaa,\"CPU, fast\",12,h12,total,10,2000,2000,2000,2000,0,0
+2,bbb,\"CPU, fast\",11,h11,total,10,1050,1050,1050,1050,0,0
+2,bbb,\"CPU, fast\",11,h11,part1,10,600,600,600,600,0,0
+2,bbb,\"CPU, fast\",12,h12-other,total,10,9000,9000,9000,9000,0,0";
+
+    #[test]
+    fn test_parse() -> Result<()> {
+        let entries = parse(HISTORY)?;
+        assert_eq!(entries.len(), 6);
+        assert_eq!(entries[0].cpu, "CPU, fast");
+        assert_eq!(entries[1].stats.median, Duration::from_nanos(400));
+        assert!(parse("1,aaa,cpu,11").is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_compare() -> Result<()> {
+        let entries = parse(HISTORY)?;
+        let comparison = compare(&entries, None, None, 10.0)?;
+        assert_eq!(comparison.baseline, "aaa");
+        assert_eq!(comparison.current, "bbb");
+
+        // Day 12 was run on another input, so it can't be compared
+        assert_eq!(comparison.changes.len(), 2);
+        let regressions = comparison.regressions().collect::<Vec<_>>();
+        assert_eq!(regressions.len(), 1);
+        assert_eq!(
+            (regressions[0].day, regressions[0].phase.as_str()),
+            (11, "part1")
+        );
+
+        assert!(compare(&entries, Some("aa"), Some("bbb"), 60.0)?
+            .regressions()
+            .next()
+            .is_none());
+        assert!(compare(&entries, Some("ccc"), None, 10.0).is_err());
+        Ok(())
+    }
+}
@@ -0,0 +1,213 @@
+//! Statistical benchmarking of a repeatable run: warmup, an adaptive number of samples and a
+//! summary of the sample distribution for the whole run and for each of its phases.
+
+use std::fmt;
+use std::time::{Duration, Instant};
+
+use crate::{as_ms, Timer};
+
+pub mod history;
+mod results;
+
+pub use results::{DayResult, Environment, Results};
+
+#[derive(Debug, Clone, Copy)]
+pub struct Config {
+    /// How long to run before sampling starts, also used to estimate the time per run.
+    pub warmup: Duration,
+    /// Roughly how long to spend sampling, bounded by `min_samples` and `max_samples`.
+    pub measurement: Duration,
+    pub min_samples: usize,
+    pub max_samples: usize,
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Self {
+            warmup: Duration::from_millis(500),
+            measurement: Duration::from_secs(3),
+            min_samples: 10,
+            max_samples: 10_000,
+        }
+    }
+}
+
+impl Config {
+    fn samples(&self, per_run: Duration) -> usize {
+        let samples = self.measurement.as_nanos() / per_run.as_nanos().max(1);
+        (samples.min(usize::MAX as u128) as usize).clamp(self.min_samples, self.max_samples)
+    }
+}
+
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct Stats {
+    pub samples: usize,
+    pub median: Duration,
+    pub mean: Duration,
+    pub min: Duration,
+    pub max: Duration,
+    pub stddev: Duration,
+    /// Samples outside the Tukey fences, 1.5 IQR below the first or above the third quartile.
+    pub outliers: usize,
+}
+
+impl Stats {
+    pub fn new(samples: &[Duration]) -> Self {
+        if samples.is_empty() {
+            return Self::default();
+        }
+
+        let mut sorted = samples.to_vec();
+        sorted.sort();
+        let n = sorted.len();
+        let nanos = sorted
+            .iter()
+            .map(|d| d.as_nanos() as f64)
+            .collect::<Vec<_>>();
+
+        let mean = nanos.iter().sum::<f64>() / n as f64;
+        let variance = if n > 1 {
+            nanos.iter().map(|v| (v - mean).powi(2)).sum::<f64>() / (n - 1) as f64
+        } else {
+            0.0
+        };
+
+        let q1 = quantile(&nanos, 0.25);
+        let q3 = quantile(&nanos, 0.75);
+        let iqr = q3 - q1;
+        let outliers = nanos
+            .iter()
+            .filter(|&&v| v < q1 - 1.5 * iqr || v > q3 + 1.5 * iqr)
+            .count();
+
+        Self {
+            samples: n,
+            median: from_nanos(quantile(&nanos, 0.5)),
+            mean: from_nanos(mean),
+            min: sorted[0],
+            max: sorted[n - 1],
+            stddev: from_nanos(variance.sqrt()),
+            outliers,
+        }
+    }
+}
+
+impl fmt::Display for Stats {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{:.4}ms median (min: {:.4}ms, max: {:.4}ms, mean: {:.4}ms, stddev: {:.4}ms, outliers: {}/{})",
+            as_ms(self.median),
+            as_ms(self.min),
+            as_ms(self.max),
+            as_ms(self.mean),
+            as_ms(self.stddev),
+            self.outliers,
+            self.samples
+        )
+    }
+}
+
+/// Linear interpolation between the closest ranks of already sorted values.
+fn quantile(sorted: &[f64], q: f64) -> f64 {
+    let rank = q * (sorted.len() - 1) as f64;
+    let lo = rank.floor() as usize;
+    let hi = rank.ceil() as usize;
+    sorted[lo] + (sorted[hi] - sorted[lo]) * (rank - lo as f64)
+}
+
+fn from_nanos(nanos: f64) -> Duration {
+    Duration::from_nanos(nanos.round() as u64)
+}
+
+#[derive(Debug, Clone)]
+pub struct Report {
+    pub total: Stats,
+    pub phases: Vec<(&'static str, Stats)>,
+}
+
+impl fmt::Display for Report {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "It took: {}", self.total)?;
+        for (name, stats) in &self.phases {
+            write!(f, "\n  {name}: {stats}")?;
+        }
+        Ok(())
+    }
+}
+
+/// Runs `f` repeatedly according to `config` and returns the result of the last run together
+/// with statistics over the sampled runs.
+pub fn bench<F, S, T>(config: &Config, f: F) -> Result<(S, Report), T>
+where
+    F: Fn(&mut Timer) -> Result<S, T>,
+{
+    let start = Instant::now();
+    let mut res = f(&mut Timer::default())?;
+    let mut runs = 1;
+    while start.elapsed() < config.warmup {
+        res = f(&mut Timer::default())?;
+        runs += 1;
+    }
+    let samples = config.samples(start.elapsed() / runs);
+
+    let mut totals = Vec::with_capacity(samples);
+    let mut phases: Vec<(&'static str, Vec<Duration>)> = vec![];
+
+    for _ in 0..samples {
+        let mut timer = Timer::default();
+        let start = Instant::now();
+        res = f(&mut timer)?;
+        totals.push(start.elapsed());
+
+        for (name, dur) in timer.phases() {
+            match phases.iter_mut().find(|(n, _)| *n == name) {
+                Some((_, durs)) => durs.push(dur),
+                None => phases.push((name, vec![dur])),
+            }
+        }
+    }
+
+    let report = Report {
+        total: Stats::new(&totals),
+        phases: phases
+            .into_iter()
+            .map(|(name, durs)| (name, Stats::new(&durs)))
+            .collect(),
+    };
+    Ok((res, report))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn ms(ms: &[u64]) -> Vec<Duration> {
+        ms.iter().map(|&ms| Duration::from_millis(ms)).collect()
+    }
+
+    #[test]
+    fn test_stats() {
+        let stats = Stats::new(&ms(&[5, 1, 3, 2, 4, 100]));
+        assert_eq!(stats.samples, 6);
+        assert_eq!(stats.min, Duration::from_millis(1));
+        assert_eq!(stats.max, Duration::from_millis(100));
+        assert_eq!(stats.median, Duration::from_micros(3500));
+        assert_eq!(stats.mean, Duration::from_nanos(19_166_667));
+        assert_eq!(stats.outliers, 1);
+
+        assert_eq!(Stats::new(&ms(&[7])).stddev, Duration::ZERO);
+        assert_eq!(Stats::new(&[]), Stats::default());
+    }
+
+    #[test]
+    fn test_samples() {
+        let config = Config {
+            measurement: Duration::from_secs(1),
+            ..Config::default()
+        };
+        assert_eq!(config.samples(Duration::from_millis(10)), 100);
+        assert_eq!(config.samples(Duration::from_secs(2)), 10);
+        assert_eq!(config.samples(Duration::ZERO), 10_000);
+    }
+}
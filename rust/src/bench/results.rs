@@ -0,0 +1,311 @@
+use std::fmt::Write;
+use std::fs;
+use std::process::Command;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use super::{Report, Stats};
+use crate::{as_ms, json, Answers};
+
+/// Where and when a set of benchmark results were produced.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Environment {
+    /// Seconds since the Unix epoch.
+    pub timestamp: u64,
+    pub cpu: String,
+    pub commit: Option<String>,
+    /// Whether the working tree had uncommitted changes.
+    pub dirty: bool,
+}
+
+impl Environment {
+    pub fn detect() -> Self {
+        let cpu = fs::read_to_string("/proc/cpuinfo")
+            .ok()
+            .and_then(|info| {
+                info.lines()
+                    .find(|l| l.starts_with("model name"))
+                    .and_then(|l| l.split(':').nth(1))
+                    .map(|s| s.trim().to_string())
+            })
+            .unwrap_or_else(|| "unknown".to_string());
+
+        let commit = git(&["rev-parse", "--short=12", "HEAD"]);
+        let dirty =
+            git(&["status", "--porcelain", "--untracked-files=no"]).is_some_and(|s| !s.is_empty());
+
+        let timestamp = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map(|d| d.as_secs())
+            .unwrap_or_default();
+
+        Self {
+            timestamp,
+            cpu,
+            commit,
+            dirty,
+        }
+    }
+
+    /// The commit hash, suffixed with `-dirty` when there were uncommitted changes.
+    pub fn commit_label(&self) -> String {
+        let commit = self.commit.as_deref().unwrap_or("unknown");
+        if self.dirty {
+            format!("{commit}-dirty")
+        } else {
+            commit.to_string()
+        }
+    }
+}
+
+fn git(args: &[&str]) -> Option<String> {
+    let output = Command::new("git").args(args).output().ok()?;
+    output
+        .status
+        .success()
+        .then(|| String::from_utf8_lossy(&output.stdout).trim().to_string())
+}
+
+#[derive(Debug, Clone)]
+pub struct DayResult {
+    pub day: u8,
+    pub input_hash: String,
+    pub answers: Answers,
+    pub report: Report,
+}
+
+impl DayResult {
+    fn phase(&self, name: &str) -> Option<&Stats> {
+        self.report
+            .phases
+            .iter()
+            .find(|(n, _)| *n == name)
+            .map(|(_, stats)| stats)
+    }
+
+    /// The whole run first, followed by each phase.
+    fn all_stats(&self) -> impl Iterator<Item = (&str, &Stats)> {
+        [("total", &self.report.total)]
+            .into_iter()
+            .chain(self.report.phases.iter().map(|(n, s)| (*n, s)))
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Results {
+    pub env: Environment,
+    pub days: Vec<DayResult>,
+}
+
+pub(crate) const CSV_HEADER: &str = "timestamp,commit,cpu,day,input_hash,phase,samples,median_ns,mean_ns,min_ns,max_ns,stddev_ns,outliers";
+
+const PHASES: [&str; 4] = ["read", "parse", "part1", "part2"];
+
+impl Results {
+    pub fn to_json(&self) -> String {
+        let mut out = String::new();
+        let _ = writeln!(out, "{{");
+        let _ = writeln!(out, "  \"timestamp\": {},", self.env.timestamp);
+        let _ = writeln!(out, "  \"cpu\": {},", json::string(&self.env.cpu));
+        let _ = writeln!(
+            out,
+            "  \"commit\": {},",
+            self.env
+                .commit
+                .as_deref()
+                .map_or("null".to_string(), json::string)
+        );
+        let _ = writeln!(out, "  \"dirty\": {},", self.env.dirty);
+        let _ = writeln!(out, "  \"days\": [");
+
+        for (i, day) in self.days.iter().enumerate() {
+            let _ = writeln!(out, "    {{");
+            let _ = writeln!(out, "      \"day\": {},", day.day);
+            let _ = writeln!(
+                out,
+                "      \"input_hash\": {},",
+                json::string(&day.input_hash)
+            );
+            let _ = writeln!(
+                out,
+                "      \"part1\": {},",
+                day.answers
+                    .part1
+                    .as_deref()
+                    .map_or("null".to_string(), json::string)
+            );
+            let _ = writeln!(
+                out,
+                "      \"part2\": {},",
+                day.answers
+                    .part2
+                    .as_deref()
+                    .map_or("null".to_string(), json::string)
+            );
+            let stats = day
+                .all_stats()
+                .map(|(name, stats)| {
+                    format!("        {}: {}", json::string(name), stats_json(stats))
+                })
+                .collect::<Vec<_>>();
+            let _ = writeln!(
+                out,
+                "      \"timings\": {{\n{}\n      }}",
+                stats.join(",\n")
+            );
+            let sep = if i + 1 < self.days.len() { "," } else { "" };
+            let _ = writeln!(out, "    }}{sep}");
+        }
+
+        let _ = writeln!(out, "  ]");
+        let _ = writeln!(out, "}}");
+        out
+    }
+
+    /// One row per day and phase, with the run as a whole as the `total` phase. Times are in
+    /// nanoseconds.
+    pub fn to_csv(&self) -> String {
+        let mut out = format!("{CSV_HEADER}\n");
+        out.push_str(&self.csv_rows());
+        out
+    }
+
+    /// The CSV rows without the header, for appending to an existing file.
+    pub(crate) fn csv_rows(&self) -> String {
+        let mut out = String::new();
+        for day in &self.days {
+            for (phase, s) in day.all_stats() {
+                let _ = writeln!(
+                    out,
+                    "{},{},{},{},{},{},{},{},{},{},{},{},{}",
+                    self.env.timestamp,
+                    csv_field(&self.env.commit_label()),
+                    csv_field(&self.env.cpu),
+                    day.day,
+                    day.input_hash,
+                    phase,
+                    s.samples,
+                    s.median.as_nanos(),
+                    s.mean.as_nanos(),
+                    s.min.as_nanos(),
+                    s.max.as_nanos(),
+                    s.stddev.as_nanos(),
+                    s.outliers
+                );
+            }
+        }
+        out
+    }
+
+    /// The table kept in the README, with median times.
+    pub fn to_markdown(&self) -> String {
+        let mut out = String::new();
+        let _ = writeln!(out, "**CPU:** {}  ", self.env.cpu);
+        let _ = writeln!(out, "**Commit:** {}", self.env.commit_label());
+        let _ = writeln!(out);
+        let _ = writeln!(
+            out,
+            "| Day | Median runtime both parts | Read | Parse | Part 1 | Part 2 |"
+        );
+        let _ = writeln!(
+            out,
+            "| --- | -------------------------:| ----:| -----:| ------:| ------:|"
+        );
+
+        for day in &self.days {
+            let phases = PHASES
+                .iter()
+                .map(|name| {
+                    day.phase(name)
+                        .map(|s| format!("{:.4}ms", as_ms(s.median)))
+                        .unwrap_or_default()
+                })
+                .collect::<Vec<_>>();
+            let _ = writeln!(
+                out,
+                "| {:02} | {:.4}ms | {} |",
+                day.day,
+                as_ms(day.report.total.median),
+                phases.join(" | ")
+            );
+        }
+        out
+    }
+}
+
+fn stats_json(s: &Stats) -> String {
+    format!(
+        "{{\"samples\": {}, \"median_ns\": {}, \"mean_ns\": {}, \"min_ns\": {}, \"max_ns\": {}, \"stddev_ns\": {}, \"outliers\": {}}}",
+        s.samples,
+        s.median.as_nanos(),
+        s.mean.as_nanos(),
+        s.min.as_nanos(),
+        s.max.as_nanos(),
+        s.stddev.as_nanos(),
+        s.outliers
+    )
+}
+
+fn csv_field(s: &str) -> String {
+    if s.contains([',', '"', '\n']) {
+        format!("\"{}\"", s.replace('"', "\"\""))
+    } else {
+        s.to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use super::*;
+
+    fn results() -> Results {
+        let stats = |ms| Stats::new(&[Duration::from_millis(ms)]);
+        Results {
+            env: Environment {
+                timestamp: 1702512000,
+                cpu: "Some CPU, 8 cores".to_string(),
+                commit: Some("abc123".to_string()),
+                dirty: false,
+            },
+            days: vec![DayResult {
+                day: 5,
+                input_hash: "d41d8cd98f00b204e9800998ecf8427e".to_string(),
+                answers: Answers {
+                    part1: Some("35".to_string()),
+                    part2: Some("46".to_string()),
+                },
+                report: Report {
+                    total: stats(3),
+                    phases: vec![("parse", stats(1)), ("part1", stats(2))],
+                },
+            }],
+        }
+    }
+
+    #[test]
+    fn test_csv() {
+        let csv = results().to_csv();
+        let lines = csv.lines().collect::<Vec<_>>();
+        assert_eq!(lines.len(), 4);
+        assert_eq!(
+            lines[1],
+            "1702512000,abc123,\"Some CPU, 8 cores\",5,d41d8cd98f00b204e9800998ecf8427e,total,1,3000000,3000000,3000000,3000000,0,0"
+        );
+        assert!(lines[2].contains(",d41d8cd98f00b204e9800998ecf8427e,parse,"));
+    }
+
+    #[test]
+    fn test_markdown() {
+        let md = results().to_markdown();
+        assert!(md.contains("| 05 | 3.0000ms |  | 1.0000ms | 2.0000ms |  |"));
+    }
+
+    #[test]
+    fn test_json() {
+        let json = results().to_json();
+        assert!(json.contains("\"cpu\": \"Some CPU, 8 cores\","));
+        assert!(json.contains("\"part2\": \"46\","));
+        assert!(json.contains("\"parse\": {\"samples\": 1, \"median_ns\": 1000000,"));
+    }
+}
@@ -0,0 +1,549 @@
+use std::env;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+use anyhow::{bail, Context, Result};
+
+use utils::answers::{self, Expected, Registry, Status};
+use utils::bench::{self, history, Config, DayResult, Environment, Results};
+use utils::input::Source;
+use utils::runner::{self, Mode};
+use utils::scaffold::{self, Shape};
+use utils::viz::{Target, Visualizer};
+use utils::{days, input_hash, Answers, Day, Parts, Timer};
+
+enum Command {
+    Run {
+        parts: Parts,
+        format: Format,
+        quiet: bool,
+        visualize: Option<Target>,
+    },
+    Bench {
+        config: Config,
+        format: Format,
+        output: Option<PathBuf>,
+        record: bool,
+        history: PathBuf,
+    },
+    Compare {
+        history: PathBuf,
+        baseline: Option<String>,
+        current: Option<String>,
+        threshold: f64,
+    },
+    Verify {
+        answers: PathBuf,
+        record: bool,
+    },
+    RunAll {
+        mode: Mode,
+        answers: PathBuf,
+    },
+    NewDay {
+        shape: Shape,
+        title: String,
+    },
+}
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum Format {
+    Text,
+    Json,
+    Csv,
+    Markdown,
+}
+
+struct Args {
+    command: Command,
+    spec: String,
+    inputs: Vec<Source>,
+}
+
+const USAGE: &str = "Usage:
+  aoc [run] [DAYS] [INPUT...] [--part 1|2|both] [--format text|json] [--quiet]
+            [--visualize] [--frames DIR]
+  aoc bench [DAYS] [INPUT...] [--format text|json|csv|markdown] [--output FILE]
+            [--warmup MS] [--measure MS] [--samples N] [--record] [--history FILE]
+  aoc compare [DAYS] [--baseline COMMIT] [--current COMMIT] [--threshold PERCENT]
+              [--history FILE]
+  aoc verify [DAYS] [INPUT...] [--answers FILE] [--record]
+  aoc run-all [DAYS] [INPUT...] [--threads N] [--sequential] [--answers FILE]
+  aoc new-day DAY [--lines|--grid|--blocks] [--title TITLE]
+
+DAYS is `all` (the default), a single day like `5` or a range like `3-7`.
+INPUT defaults to ../input/dayNN, `-` reads stdin and several inputs are run one after another.
+With --quiet, run prints only the answers to stdout and the timings to stderr.
+--visualize shows the days' state on the terminal, --frames DIR writes it to files instead.
+run-all solves the days concurrently, on one thread per CPU unless --threads is given, and
+prints a summary table. --sequential runs them one at a time instead, for clean timings.
+new-day creates src/days/dayNN.rs for the given input shape (lines by default), registers it
+and creates empty ../input/dayNN and ../input/examples/dayNN files.";
+
+fn parse_args() -> Result<Args> {
+    let mut args = env::args().skip(1).peekable();
+
+    let run = Command::Run {
+        parts: Parts::Both,
+        format: Format::Text,
+        quiet: false,
+        visualize: None,
+    };
+    let mut command = match args.peek().map(String::as_str) {
+        Some("run") => {
+            args.next();
+            run
+        }
+        Some("bench") => {
+            args.next();
+            Command::Bench {
+                config: Config::default(),
+                format: Format::Text,
+                output: None,
+                record: false,
+                history: PathBuf::from(history::DEFAULT_PATH),
+            }
+        }
+        Some("compare") => {
+            args.next();
+            Command::Compare {
+                history: PathBuf::from(history::DEFAULT_PATH),
+                baseline: None,
+                current: None,
+                threshold: 10.0,
+            }
+        }
+        Some("verify") => {
+            args.next();
+            Command::Verify {
+                answers: PathBuf::from(answers::DEFAULT_PATH),
+                record: false,
+            }
+        }
+        Some("run-all") => {
+            args.next();
+            Command::RunAll {
+                mode: Mode::Parallel { threads: None },
+                answers: PathBuf::from(answers::DEFAULT_PATH),
+            }
+        }
+        Some("new-day") => {
+            args.next();
+            Command::NewDay {
+                shape: Shape::default(),
+                title: String::new(),
+            }
+        }
+        _ => run,
+    };
+    let mut spec = None;
+    let mut inputs = vec![];
+
+    while let Some(arg) = args.next() {
+        let mut value = |name: &str| -> Result<String> {
+            args.next()
+                .with_context(|| format!("No value given for {name}"))
+        };
+
+        match (&mut command, arg.as_str()) {
+            (_, "-h" | "--help") => {
+                println!("{USAGE}");
+                std::process::exit(0);
+            }
+            (Command::Bench { config, .. }, "--warmup") => {
+                config.warmup = Duration::from_millis(parse_value(&value(&arg)?, &arg)?);
+            }
+            (Command::Bench { config, .. }, "--measure") => {
+                config.measurement = Duration::from_millis(parse_value(&value(&arg)?, &arg)?);
+            }
+            (Command::Bench { config, .. }, "--samples") => {
+                let samples = parse_value(&value(&arg)?, &arg)?;
+                config.min_samples = samples;
+                config.max_samples = samples;
+            }
+            (Command::Run { parts, .. }, "--part") => *parts = value(&arg)?.parse()?,
+            (Command::Run { format, .. }, "--format") => {
+                *format = match value(&arg)?.as_str() {
+                    "text" => Format::Text,
+                    "json" => Format::Json,
+                    other => bail!("Unknown format {other}, run supports text and json"),
+                };
+            }
+            (Command::Run { quiet, .. }, "--quiet" | "-q") => *quiet = true,
+            (Command::Run { visualize, .. }, "--visualize") => *visualize = Some(Target::Terminal),
+            (Command::Run { visualize, .. }, "--frames") => {
+                *visualize = Some(Target::Dir(PathBuf::from(value(&arg)?)));
+            }
+            (Command::Bench { format, .. }, "--format") => {
+                *format = match value(&arg)?.as_str() {
+                    "text" => Format::Text,
+                    "json" => Format::Json,
+                    "csv" => Format::Csv,
+                    "markdown" | "md" => Format::Markdown,
+                    other => bail!("Unknown format {other}"),
+                };
+            }
+            (Command::Bench { output, .. }, "--output") => {
+                *output = Some(PathBuf::from(value(&arg)?));
+            }
+            (Command::Bench { record, .. } | Command::Verify { record, .. }, "--record") => {
+                *record = true;
+            }
+            (Command::RunAll { mode, .. }, "--threads") => {
+                let threads = parse_value(&value(&arg)?, &arg)?;
+                if threads == 0 {
+                    bail!("--threads must be at least 1");
+                }
+                if *mode == Mode::Sequential {
+                    bail!("--threads can't be combined with --sequential");
+                }
+                *mode = Mode::Parallel {
+                    threads: Some(threads),
+                };
+            }
+            (Command::RunAll { mode, .. }, "--sequential") => {
+                if let Mode::Parallel { threads: Some(_) } = mode {
+                    bail!("--sequential can't be combined with --threads");
+                }
+                *mode = Mode::Sequential;
+            }
+            (Command::NewDay { shape, .. }, "--lines" | "--grid" | "--blocks") => {
+                *shape = arg[2..].parse()?;
+            }
+            (Command::NewDay { title, .. }, "--title") => *title = value(&arg)?,
+            (Command::Verify { answers, .. } | Command::RunAll { answers, .. }, "--answers") => {
+                *answers = PathBuf::from(value(&arg)?);
+            }
+            (Command::Bench { history, .. } | Command::Compare { history, .. }, "--history") => {
+                *history = PathBuf::from(value(&arg)?);
+            }
+            (Command::Compare { baseline, .. }, "--baseline") => *baseline = Some(value(&arg)?),
+            (Command::Compare { current, .. }, "--current") => *current = Some(value(&arg)?),
+            (Command::Compare { threshold, .. }, "--threshold") => {
+                *threshold = parse_value(&value(&arg)?, &arg)?;
+            }
+            _ if arg.starts_with("--") => bail!("Unknown option {arg}\n\n{USAGE}"),
+            _ if spec.is_none() => spec = Some(arg),
+            _ => inputs.push(Source::from(arg.as_str())),
+        }
+    }
+
+    Ok(Args {
+        command,
+        spec: spec.unwrap_or_else(|| "all".to_string()),
+        inputs,
+    })
+}
+
+fn parse_value<T: std::str::FromStr>(value: &str, name: &str) -> Result<T> {
+    value
+        .parse()
+        .ok()
+        .with_context(|| format!("Invalid value {value} for {name}"))
+}
+
+fn print_answers(answers: &Answers) {
+    for (part, answer) in answers.parts() {
+        println!("Part{part}: {answer}");
+    }
+}
+
+type Job = (&'static Day, Source);
+
+fn print_header(jobs: &[Job], (day, source): &Job) {
+    if jobs.len() > 1 {
+        println!("Day {:02} ({source})", day.day);
+    }
+}
+
+/// Runs each day once. Quiet runs keep stdout to the answers alone, one per line or as JSON
+/// without timings, and report the timings on stderr.
+fn run(
+    jobs: &[Job],
+    parts: Parts,
+    format: Format,
+    quiet: bool,
+    visualize: &Option<Target>,
+) -> Result<()> {
+    let mut json = vec![];
+
+    for job @ (day, source) in jobs {
+        if quiet && jobs.len() > 1 {
+            eprintln!("Day {:02} ({source})", day.day);
+        } else if !quiet && format == Format::Text {
+            print_header(jobs, job);
+        }
+
+        let run = day.run_timed(source, parts)?;
+        match (format, quiet) {
+            (Format::Json, _) => json.push(run.to_json(!quiet)),
+            (_, true) => run
+                .answers
+                .parts()
+                .for_each(|(_, answer)| println!("{answer}")),
+            (_, false) => print_answers(&run.answers),
+        }
+        match (format, quiet) {
+            (_, true) => eprintln!("{}", run.timing()),
+            (Format::Text, false) => println!("{}", run.timing()),
+            _ => {}
+        }
+
+        if let Some(target) = visualize {
+            let mut viz = Visualizer::new(target.clone(), day.day);
+            day.visualize(source, &mut viz)?;
+            match (target, viz.frames()) {
+                (_, 0) => eprintln!("Day {:02} has no visualization", day.day),
+                (Target::Dir(dir), frames) => {
+                    eprintln!("Wrote {frames} frame(s) to {}", dir.display())
+                }
+                _ => {}
+            }
+        }
+    }
+
+    if format == Format::Json {
+        println!("[\n  {}\n]", json.join(",\n  "));
+    }
+    Ok(())
+}
+
+fn bench(
+    jobs: &[Job],
+    config: &Config,
+    format: Format,
+    output: &Option<PathBuf>,
+    record: bool,
+    history_path: &Path,
+) -> Result<()> {
+    let mut results = Results {
+        env: Environment::detect(),
+        days: vec![],
+    };
+
+    for job @ (day, source) in jobs {
+        if format == Format::Text {
+            print_header(jobs, job);
+        } else {
+            eprintln!("Benchmarking day {:02} ({source})", day.day);
+        }
+        let input_hash = input_hash(source.read()?.as_bytes());
+
+        let (answers, report) = bench::bench(config, |timer| day.run(source, timer))?;
+        if format == Format::Text {
+            print_answers(&answers);
+            println!("{report}");
+        }
+        results.days.push(DayResult {
+            day: day.day,
+            input_hash,
+            answers,
+            report,
+        });
+    }
+
+    if record {
+        history::append(history_path, &results)?;
+        eprintln!("Recorded results in {}", history_path.display());
+    }
+
+    let rendered = match format {
+        Format::Text => return Ok(()),
+        Format::Json => results.to_json(),
+        Format::Csv => results.to_csv(),
+        Format::Markdown => results.to_markdown(),
+    };
+
+    match output {
+        Some(path) => fs::write(path, rendered)
+            .with_context(|| format!("Unable to write {}", path.display()))?,
+        None => print!("{rendered}"),
+    }
+    Ok(())
+}
+
+fn compare(
+    selected: &[&Day],
+    history_path: &Path,
+    baseline: Option<&str>,
+    current: Option<&str>,
+    threshold: f64,
+) -> Result<()> {
+    let entries = history::load(history_path)?;
+    let mut comparison = history::compare(&entries, baseline, current, threshold)?;
+    comparison
+        .changes
+        .retain(|c| selected.iter().any(|d| d.day == c.day));
+
+    print!("{comparison}");
+
+    let regressions = comparison.regressions().count();
+    if regressions > 0 {
+        bail!("{regressions} phase(s) got more than {threshold}% slower");
+    }
+    Ok(())
+}
+
+/// Runs each day and checks its answers against the known ones. With `record`, answers that
+/// aren't known yet are added. Days `skipped` for lack of an input are listed at the end.
+fn verify(jobs: &[Job], skipped: &[Job], answers_path: &Path, record: bool) -> Result<()> {
+    let registry = Registry::load(answers_path)?;
+    let mut failed = 0;
+    let mut new = vec![];
+
+    for (day, source) in jobs {
+        let input_hash = input_hash(source.read()?.as_bytes());
+        let answers = day.run(source, &mut Timer::default())?;
+
+        for (part, answer) in answers.parts() {
+            let status = registry.check(day.day, part, &input_hash, answer);
+            match &status {
+                Status::Fail { expected } => {
+                    failed += 1;
+                    println!(
+                        "Day {:02} part {part}: {status} {answer} (expected {expected})",
+                        day.day
+                    );
+                }
+                _ => println!("Day {:02} part {part}: {status} {answer}", day.day),
+            }
+
+            if status == Status::Missing {
+                new.push(Expected {
+                    day: day.day,
+                    part,
+                    input_hash: input_hash.clone(),
+                    answer: answer.to_string(),
+                });
+            }
+        }
+    }
+    for (day, source) in skipped {
+        println!("Day {:02}: SKIPPED, {source} has no input yet", day.day);
+    }
+
+    if record && !new.is_empty() {
+        Registry::append(answers_path, &new)?;
+        println!(
+            "Recorded {} answer(s) in {}",
+            new.len(),
+            answers_path.display()
+        );
+    }
+    if failed > 0 {
+        bail!("{failed} answer(s) didn't match");
+    }
+    Ok(())
+}
+
+/// Runs all days as `mode` says and prints a summary, failing if any day errored or gave a wrong
+/// answer. Days `skipped` for lack of an input are listed after the summary.
+fn run_all(jobs: &[Job], skipped: &[Job], mode: Mode, answers_path: &Path) -> Result<()> {
+    let registry = Registry::load(answers_path)?;
+    let summary = runner::run_all(jobs, mode, &registry)?;
+    println!("{summary}");
+    if !skipped.is_empty() {
+        let days = skipped
+            .iter()
+            .map(|(day, _)| format!("{:02}", day.day))
+            .collect::<Vec<_>>();
+        println!("Skipped day(s) without an input: {}", days.join(", "));
+    }
+
+    let failed = summary.failed();
+    if failed > 0 {
+        bail!("{failed} day(s) failed");
+    }
+    Ok(())
+}
+
+/// Creates and registers the skeleton of a new day in the crate in the working directory.
+fn new_day(spec: &str, shape: Shape, title: &str) -> Result<()> {
+    let day = parse_value(spec, "DAY")?;
+    for path in scaffold::create(Path::new(""), day, title, shape)? {
+        println!("Wrote {}", path.display());
+    }
+    println!("Paste the example into ../input/examples/day{day:02} and the input into ../input/day{day:02}");
+    Ok(())
+}
+
+/// The selected days with the given inputs, or with their default inputs. Days whose default
+/// input is missing or empty are skipped when running several days, and returned second.
+fn jobs(selected: &[&'static Day], inputs: &[Source]) -> Result<(Vec<Job>, Vec<Job>)> {
+    match (selected, inputs) {
+        (&[day], []) if !day.default_input().has_input() => bail!(
+            "No input for day {:02} yet, put it in {} or give an input file",
+            day.day,
+            day.default_input()
+        ),
+        (_, []) => Ok(selected
+            .iter()
+            .map(|&day| (day, day.default_input()))
+            .partition(|(_, source)| source.has_input())),
+        (&[day], inputs) => Ok((inputs.iter().map(|i| (day, i.clone())).collect(), vec![])),
+        _ => bail!("Input files can only be given when running a single day"),
+    }
+}
+
+fn main() -> Result<()> {
+    let args = parse_args()?;
+    if let Command::NewDay { shape, title } = &args.command {
+        return new_day(&args.spec, *shape, title);
+    }
+
+    let selected = days::select(&args.spec)?;
+    let jobs = || jobs(&selected, &args.inputs);
+    // Where the output is meant for scripts, skipped days are only mentioned on stderr.
+    let jobs_noting_skipped = || -> Result<Vec<Job>> {
+        let (jobs, skipped) = jobs()?;
+        for (day, source) in skipped {
+            eprintln!("Skipping day {:02}, {source} has no input yet", day.day);
+        }
+        Ok(jobs)
+    };
+
+    match &args.command {
+        Command::Run {
+            parts,
+            format,
+            quiet,
+            visualize,
+        } => run(&jobs_noting_skipped()?, *parts, *format, *quiet, visualize),
+        Command::Bench {
+            config,
+            format,
+            output,
+            record,
+            history,
+        } => bench(
+            &jobs_noting_skipped()?,
+            config,
+            *format,
+            output,
+            *record,
+            history,
+        ),
+        Command::Compare {
+            history,
+            baseline,
+            current,
+            threshold,
+        } => compare(
+            &selected,
+            history,
+            baseline.as_deref(),
+            current.as_deref(),
+            *threshold,
+        ),
+        Command::Verify { answers, record } => {
+            let (jobs, skipped) = jobs()?;
+            verify(&jobs, &skipped, answers, *record)
+        }
+        Command::RunAll { mode, answers } => {
+            let (jobs, skipped) = jobs()?;
+            run_all(&jobs, &skipped, *mode, answers)
+        }
+        Command::NewDay { .. } => unreachable!("new-day doesn't run any days"),
+    }
+}
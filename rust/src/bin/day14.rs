@@ -0,0 +1,5 @@
+//! Day 14 on its own, the same as `aoc 14`.
+
+fn main() -> anyhow::Result<()> {
+    utils::days::run_bin(14)
+}
@@ -0,0 +1,5 @@
+//! Day 15 on its own, the same as `aoc 15`.
+
+fn main() -> anyhow::Result<()> {
+    utils::days::run_bin(15)
+}
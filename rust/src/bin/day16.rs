@@ -0,0 +1,5 @@
+//! Day 16 on its own, the same as `aoc 16`.
+
+fn main() -> anyhow::Result<()> {
+    utils::days::run_bin(16)
+}
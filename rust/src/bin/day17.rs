@@ -0,0 +1,5 @@
+//! Day 17 on its own, the same as `aoc 17`.
+
+fn main() -> anyhow::Result<()> {
+    utils::days::run_bin(17)
+}
@@ -0,0 +1,5 @@
+//! Day 18 on its own, the same as `aoc 18`.
+
+fn main() -> anyhow::Result<()> {
+    utils::days::run_bin(18)
+}
@@ -0,0 +1,96 @@
+//! Cycle detection for a state that is repeatedly stepped, so the state after a huge number of
+//! steps can be found from the first time a state repeats.
+
+use std::collections::HashMap;
+use std::hash::Hash;
+
+/// From the state at index `start` on, the states repeat every `len` steps.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Cycle {
+    pub start: usize,
+    pub len: usize,
+}
+
+impl Cycle {
+    /// The index of the first state equal to the state at index `n`.
+    pub fn reduce(&self, n: usize) -> usize {
+        if n < self.start {
+            n
+        } else {
+            self.start + (n - self.start) % self.len
+        }
+    }
+}
+
+/// Steps from `first` until a state repeats. Returns the states up to the repeat, `first` being
+/// at index 0, and the cycle.
+pub fn find<S: Hash + Eq + Clone>(first: S, step: impl FnMut(&S) -> S) -> (Vec<S>, Cycle) {
+    match walk(first, usize::MAX, step) {
+        (states, Some(cycle)) => (states, cycle),
+        (_, None) => unreachable!("only stops at a repeat"),
+    }
+}
+
+/// The state after `n` steps from `first`, skipping the whole cycles once a state repeats.
+pub fn nth<S: Hash + Eq + Clone>(first: S, n: usize, step: impl FnMut(&S) -> S) -> S {
+    let (mut states, cycle) = walk(first, n, step);
+    let i = cycle.map_or(n, |cycle| cycle.reduce(n));
+    states.swap_remove(i)
+}
+
+/// Steps until a state repeats or the state after `n` steps is known.
+fn walk<S: Hash + Eq + Clone>(
+    first: S,
+    n: usize,
+    mut step: impl FnMut(&S) -> S,
+) -> (Vec<S>, Option<Cycle>) {
+    let mut seen = HashMap::from([(first.clone(), 0)]);
+    let mut states = vec![first];
+
+    while states.len() <= n {
+        let next = step(states.last().unwrap());
+        if let Some(&start) = seen.get(&next) {
+            let len = states.len() - start;
+            return (states, Some(Cycle { start, len }));
+        }
+        seen.insert(next.clone(), states.len());
+        states.push(next);
+    }
+    (states, None)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_find() {
+        // Doubling mod 21 from 1 comes back to 1.
+        let (states, cycle) = find(1, |x| x * 2 % 21);
+        assert_eq!(states, [1, 2, 4, 8, 16, 11]);
+        assert_eq!(cycle, Cycle { start: 0, len: 6 });
+
+        // 5, 11, 2 repeat after a lead-in of 10.
+        let (states, cycle) = find(10, |&x| if x == 10 { 5 } else { (x * 2 + 1) % 21 });
+        assert_eq!(states, [10, 5, 11, 2]);
+        assert_eq!(cycle, Cycle { start: 1, len: 3 });
+        assert_eq!(cycle.reduce(0), 0);
+        assert_eq!(cycle.reduce(4), 1);
+        assert_eq!(cycle.reduce(1_000_000_000), 1);
+    }
+
+    #[test]
+    fn test_nth() {
+        let step = |&x: &u64| (x * x + 1) % 1000;
+        let mut x = 3;
+        for n in 0..200 {
+            assert_eq!(nth(3, n, step), x);
+            x = step(&x);
+        }
+        let (states, cycle) = find(3, step);
+        assert_eq!(
+            nth(3, 1_000_000_000_000, step),
+            states[cycle.reduce(1_000_000_000_000)]
+        );
+    }
+}
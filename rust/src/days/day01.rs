@@ -0,0 +1,125 @@
+//! Day 1: Trebuchet?!
+
+use anyhow::{Context, Result};
+
+use crate::Solution;
+
+pub type Input = Vec<String>;
+
+/// The digits in `s`, including the spelled out ones like `one` if `spelled`.
+fn extract_digits(s: &str, spelled: bool) -> Vec<i32> {
+    const SPELLED_DIGITS: [&str; 9] = [
+        "one", "two", "three", "four", "five", "six", "seven", "eight", "nine",
+    ];
+
+    let mut digits = vec![];
+
+    let chrs = s.chars().collect::<Vec<_>>();
+    for idx in 0..s.len() {
+        let c = chrs[idx];
+        if c.is_ascii_digit() {
+            let digit = (c as u8 - b'0') as i32;
+            digits.push(digit);
+        }
+        if !spelled {
+            continue;
+        }
+
+        for (si, sl) in SPELLED_DIGITS.iter().enumerate() {
+            if s[idx..].starts_with(sl) {
+                digits.push(si as i32 + 1)
+            }
+        }
+    }
+    digits
+}
+
+fn calibration_from_digits(digits: &[i32]) -> i32 {
+    digits.iter().next().unwrap_or(&0) * 10 + digits.iter().last().unwrap_or(&0)
+}
+
+fn calibration_sum(input: &Input, spelled: bool) -> i32 {
+    input
+        .iter()
+        .map(|s| calibration_from_digits(&extract_digits(s, spelled)))
+        .sum()
+}
+
+pub fn part1(input: &Input) -> i32 {
+    calibration_sum(input, false)
+}
+
+pub fn part2(input: &Input) -> i32 {
+    calibration_sum(input, true)
+}
+
+pub struct Day01;
+
+impl Solution for Day01 {
+    const DAY: u8 = 1;
+    type Input = Input;
+    type Output1 = i32;
+    type Output2 = i32;
+
+    fn parse(input: &str) -> Result<Input> {
+        parse(input)
+    }
+
+    fn part1(input: &Input) -> i32 {
+        part1(input)
+    }
+
+    fn part2(input: &Input) -> i32 {
+        part2(input)
+    }
+}
+
+/// Parses the puzzle input.
+pub fn parse(input: &str) -> Result<Input> {
+    input
+        .lines()
+        .map(|line| line.parse::<String>().context("Unable to parse input line"))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const INPUT1: &str = "
+        1abc2
+        pqr3stu8vwx
+        a1b2c3d4e5f
+        treb7uchet";
+
+    const INPUT2: &str = "
+        two1nine
+        eightwothree
+        abcone2threexyz
+        xtwone3four
+        4nineeightseven2
+        zoneight234
+        7pqrstsixteen";
+
+    fn as_input(s: &str) -> Result<Input> {
+        parse(
+            &s.split('\n')
+                .skip(1)
+                .map(|s| s.trim())
+                .collect::<Vec<_>>()
+                .join("\n"),
+        )
+    }
+
+    #[test]
+    fn test_part1() -> Result<()> {
+        assert_eq!(part1(&as_input(INPUT1)?), 142);
+        Ok(())
+    }
+
+    #[test]
+    fn test_part2() -> Result<()> {
+        assert_eq!(part2(&as_input(INPUT2)?), 281);
+        Ok(())
+    }
+}
@@ -0,0 +1,187 @@
+//! Day 2: Cube Conundrum
+
+use std::str::FromStr;
+
+use anyhow::Result;
+
+use crate::error::{parse_lines, ParseError, Required};
+use crate::Solution;
+
+pub type Input = Vec<Game>;
+
+#[derive(Debug, Default)]
+pub struct Cubes {
+    pub red: i32,
+    pub green: i32,
+    pub blue: i32,
+}
+
+impl Cubes {
+    pub fn values(&self) -> impl Iterator<Item = i32> {
+        [self.red, self.green, self.blue].into_iter()
+    }
+}
+
+#[derive(Debug)]
+pub struct Game {
+    pub id: i32,
+    pub revealed_cubes: Vec<Cubes>,
+}
+
+impl Game {
+    pub fn possible(&self, cubes: &Cubes) -> bool {
+        for reveal in self.revealed_cubes.iter() {
+            if reveal.red > cubes.red || reveal.green > cubes.green || reveal.blue > cubes.blue {
+                return false;
+            }
+        }
+        true
+    }
+
+    pub fn fewest_possible(&self) -> Cubes {
+        let mut fewest = Cubes::default();
+
+        for cubes in self.revealed_cubes.iter() {
+            if fewest.red < cubes.red {
+                fewest.red = cubes.red;
+            }
+            if fewest.green < cubes.green {
+                fewest.green = cubes.green;
+            }
+            if fewest.blue < cubes.blue {
+                fewest.blue = cubes.blue;
+            }
+        }
+
+        fewest
+    }
+}
+
+pub fn part1(input: &Input) -> i32 {
+    let loaded = Cubes {
+        red: 12,
+        green: 13,
+        blue: 14,
+    };
+
+    input
+        .iter()
+        .filter(|game| game.possible(&loaded))
+        .map(|game| game.id)
+        .sum()
+}
+
+pub fn part2(input: &Input) -> i32 {
+    input
+        .iter()
+        .map(|game| game.fewest_possible().values().product::<i32>())
+        .sum()
+}
+
+pub struct Day02;
+
+impl Solution for Day02 {
+    const DAY: u8 = 2;
+    type Input = Input;
+    type Output1 = i32;
+    type Output2 = i32;
+
+    fn parse(input: &str) -> Result<Input> {
+        parse(input)
+    }
+
+    fn part1(input: &Input) -> i32 {
+        part1(input)
+    }
+
+    fn part2(input: &Input) -> i32 {
+        part2(input)
+    }
+}
+
+impl FromStr for Game {
+    type Err = ParseError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut split = s.splitn(2, ':');
+        let mut id_part = split.next().required(s, "id part")?.split(' ');
+        let reveal_part = split.next().required(s, "reveal part")?.split(';');
+
+        let id = id_part.nth(1).parse_required(s, "id")?;
+        let mut revealed_cubes = vec![];
+
+        for part in reveal_part {
+            let mut cubes = Cubes::default();
+
+            for part in part.split(',') {
+                let mut split = part.trim().split(' ');
+                let count = split.next().parse_required(s, "count")?;
+                let color = split.next().required(s, "color")?;
+
+                match color {
+                    "red" => cubes.red = count,
+                    "green" => cubes.green = count,
+                    "blue" => cubes.blue = count,
+                    _ => {
+                        return Err(ParseError::new(
+                            s,
+                            color,
+                            format!("no such color `{color}`"),
+                        ))
+                    }
+                }
+            }
+
+            revealed_cubes.push(cubes);
+        }
+
+        Ok(Game { id, revealed_cubes })
+    }
+}
+
+/// Parses the puzzle input.
+pub fn parse(input: &str) -> Result<Input> {
+    Ok(parse_lines(input)?)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const INPUT: &str = "
+        Game 1: 3 blue, 4 red; 1 red, 2 green, 6 blue; 2 green
+        Game 2: 1 blue, 2 green; 3 green, 4 blue, 1 red; 1 green, 1 blue
+        Game 3: 8 green, 6 blue, 20 red; 5 blue, 4 red, 13 green; 5 green, 1 red
+        Game 4: 1 green, 3 red, 6 blue; 3 green, 6 red; 3 green, 15 blue, 14 red
+        Game 5: 6 red, 1 blue, 3 green; 2 blue, 1 red, 2 green";
+
+    fn as_input(s: &str) -> Result<Input> {
+        parse(
+            &s.split('\n')
+                .skip(1)
+                .map(|s| s.trim())
+                .collect::<Vec<_>>()
+                .join("\n"),
+        )
+    }
+
+    #[test]
+    fn test_part1() -> Result<()> {
+        assert_eq!(part1(&as_input(INPUT)?), 8);
+        Ok(())
+    }
+
+    #[test]
+    fn test_part2() -> Result<()> {
+        assert_eq!(part2(&as_input(INPUT)?), 2286);
+        Ok(())
+    }
+
+    #[test]
+    fn test_parse_error() {
+        let input = INPUT.replace("2 green; 3 green", "2 grene; 3 green");
+        let err = as_input(&input).unwrap_err();
+        let err = err.downcast_ref::<ParseError>().unwrap();
+        assert_eq!(err.message, "no such color `grene`");
+        assert_eq!((err.line, err.column, err.len), (2, 19, 5));
+    }
+}
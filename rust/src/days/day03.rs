@@ -0,0 +1,244 @@
+//! Day 3: Gear Ratios
+
+use std::collections::{HashMap, HashSet};
+use std::ops::Range;
+
+use anyhow::Result;
+
+use crate::grid::Grid;
+use crate::viz::{Color, Frame, Visualizer};
+use crate::Solution;
+
+pub type Input = Engine;
+
+#[derive(Debug)]
+pub struct Engine {
+    pub schematic: Grid<char>,
+}
+
+/// A number in the schematic: its value, row, columns and the symbols next to it.
+#[derive(Debug)]
+struct Number {
+    value: i32,
+    y: usize,
+    xs: Range<usize>,
+    symbols: HashSet<(char, usize, usize)>,
+}
+
+impl Number {
+    /// Numbers next to a symbol are part numbers.
+    fn is_part(&self) -> bool {
+        !self.symbols.is_empty()
+    }
+}
+
+impl Engine {
+    fn adjacent(&self, x: usize, y: usize) -> impl Iterator<Item = (char, usize, usize)> + '_ {
+        self.schematic
+            .neighbours8(x, y)
+            .map(|(x, y)| (self.schematic[(x, y)], x, y))
+    }
+
+    /// Every number in the schematic, with where it is and the symbols next to it.
+    fn numbers(&self) -> Vec<Number> {
+        let mut numbers = vec![];
+
+        for y in 0..self.schematic.height() {
+            let row = self.schematic.row(y);
+            let mut x = 0;
+            while x < row.len() {
+                if !Self::is_number(row[x]) {
+                    x += 1;
+                    continue;
+                }
+
+                let start = x;
+                while x < row.len() && Self::is_number(row[x]) {
+                    x += 1;
+                }
+                let symbols = (start..x)
+                    .flat_map(|x| self.adjacent(x, y))
+                    .filter(|(c, _, _)| Self::is_symbol(*c))
+                    .collect();
+
+                numbers.push(Number {
+                    value: row[start..x]
+                        .iter()
+                        .collect::<String>()
+                        .parse()
+                        .unwrap_or_default(),
+                    y,
+                    xs: start..x,
+                    symbols,
+                });
+            }
+        }
+        numbers
+    }
+
+    /// The `*`s next to exactly two part numbers, with those numbers.
+    fn gears(numbers: &[Number]) -> HashMap<(usize, usize), Vec<i32>> {
+        let mut maybe_gears = HashMap::<(usize, usize), Vec<i32>>::new();
+        for number in numbers {
+            for &(c, x, y) in &number.symbols {
+                if c == '*' {
+                    maybe_gears.entry((x, y)).or_default().push(number.value);
+                }
+            }
+        }
+        maybe_gears.retain(|_, nrs| nrs.len() == 2);
+        maybe_gears
+    }
+
+    /// The numbers next to a symbol.
+    pub fn part_numbers(&self) -> Vec<i32> {
+        self.numbers()
+            .into_iter()
+            .filter(Number::is_part)
+            .map(|number| number.value)
+            .collect()
+    }
+
+    /// For each gear, the product of its two part numbers.
+    pub fn gear_ratios(&self) -> Vec<i32> {
+        Self::gears(&self.numbers())
+            .into_values()
+            .map(|nrs| nrs.into_iter().product())
+            .collect()
+    }
+
+    /// The schematic with part numbers in green, other numbers in red, symbols in yellow and
+    /// gears in magenta.
+    pub fn frame(&self) -> Frame {
+        let mut frame = Frame::new(
+            "Part numbers green, other numbers red, symbols yellow, gears magenta",
+            &self.schematic,
+        );
+        let numbers = self.numbers();
+
+        for (pos, &c) in self.schematic.iter() {
+            if Self::is_symbol(c) {
+                frame.paint(pos, Color::Yellow);
+            }
+        }
+        for pos in Self::gears(&numbers).into_keys() {
+            frame.paint(pos, Color::Magenta);
+        }
+        for number in &numbers {
+            let color = if number.is_part() {
+                Color::Green
+            } else {
+                Color::Red
+            };
+            for x in number.xs.clone() {
+                frame.paint((x, number.y), color);
+            }
+        }
+        frame.paint_rest(Color::Dim);
+        frame
+    }
+
+    fn is_number(c: char) -> bool {
+        c.is_ascii_digit()
+    }
+
+    fn is_symbol(c: char) -> bool {
+        c != '.' && !Self::is_number(c)
+    }
+}
+
+pub fn part1(input: &Input) -> i32 {
+    input.part_numbers().into_iter().sum()
+}
+
+pub fn part2(input: &Input) -> i32 {
+    input.gear_ratios().into_iter().sum()
+}
+
+pub struct Day03;
+
+impl Solution for Day03 {
+    const DAY: u8 = 3;
+    type Input = Input;
+    type Output1 = i32;
+    type Output2 = i32;
+
+    fn parse(input: &str) -> Result<Input> {
+        parse(input)
+    }
+
+    fn part1(input: &Input) -> i32 {
+        part1(input)
+    }
+
+    fn part2(input: &Input) -> i32 {
+        part2(input)
+    }
+
+    fn visualize(input: &Input, viz: &mut Visualizer) -> Result<()> {
+        viz.show(&input.frame())
+    }
+}
+
+/// Parses the puzzle input.
+pub fn parse(input: &str) -> Result<Input> {
+    let schematic = input.parse()?;
+    Ok(Engine { schematic })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const INPUT: &str = "
+        467..114..
+        ...*......
+        ..35..633.
+        ......#...
+        617*......
+        .....+.58.
+        ..592.....
+        ......755.
+        ...$.*....
+        .664.598..";
+
+    fn as_input(s: &str) -> Result<Input> {
+        parse(
+            &s.split('\n')
+                .skip(1)
+                .map(|s| s.trim())
+                .collect::<Vec<_>>()
+                .join("\n"),
+        )
+    }
+
+    #[test]
+    fn test_part1() -> Result<()> {
+        assert_eq!(part1(&as_input(INPUT)?), 4361);
+        Ok(())
+    }
+
+    #[test]
+    fn test_part2() -> Result<()> {
+        assert_eq!(part2(&as_input(INPUT)?), 467835);
+        Ok(())
+    }
+
+    #[test]
+    fn test_frame() -> Result<()> {
+        let frame = as_input(INPUT)?.frame();
+        assert!(frame.plain().ends_with("\n...$.*....\n.664.598..\n"));
+
+        let color = |x, y| frame.cells[(x, y)].color;
+        assert_eq!(color(0, 0), Some(Color::Green));
+        assert_eq!(color(5, 0), Some(Color::Red));
+        assert_eq!(color(6, 3), Some(Color::Yellow));
+        assert_eq!(color(3, 1), Some(Color::Magenta));
+        assert_eq!(color(5, 8), Some(Color::Magenta));
+        // Next to only one part number, so not a gear.
+        assert_eq!(color(3, 4), Some(Color::Yellow));
+        assert_eq!(color(1, 0), Some(Color::Green));
+        assert_eq!(color(4, 0), Some(Color::Dim));
+        Ok(())
+    }
+}
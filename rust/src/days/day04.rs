@@ -0,0 +1,147 @@
+//! Day 4: Scratchcards
+
+use std::collections::HashSet;
+use std::str::FromStr;
+
+use anyhow::Result;
+
+use crate::error::{parse_lines, parse_token, ParseError, Required};
+use crate::Solution;
+
+pub type Input = Vec<Card>;
+
+#[derive(Debug)]
+pub struct Card {
+    pub winning_numbers: HashSet<i32>,
+    pub numbers_you_have: HashSet<i32>,
+}
+
+fn both_parts(input: &Input) -> (i32, i32) {
+    let mut p1 = 0;
+    let mut copies = vec![1; input.len()];
+
+    for (idx, card) in input.iter().enumerate() {
+        let mut points = 0;
+        let mut matches = 0;
+
+        for n in &card.numbers_you_have {
+            if card.winning_numbers.contains(n) {
+                points = if points == 0 { 1 } else { points * 2 };
+                matches += 1;
+            }
+        }
+
+        p1 += points;
+
+        for nidx in ((idx + 1)..copies.len()).take(matches) {
+            copies[nidx] += copies[idx];
+        }
+    }
+
+    (p1, copies.into_iter().sum())
+}
+
+pub fn part1(input: &Input) -> i32 {
+    both_parts(input).0
+}
+
+pub fn part2(input: &Input) -> i32 {
+    both_parts(input).1
+}
+
+pub struct Day04;
+
+impl Solution for Day04 {
+    const DAY: u8 = 4;
+    type Input = Input;
+    type Output1 = i32;
+    type Output2 = i32;
+
+    fn parse(input: &str) -> Result<Input> {
+        parse(input)
+    }
+
+    fn part1(input: &Input) -> i32 {
+        part1(input)
+    }
+
+    fn part2(input: &Input) -> i32 {
+        part2(input)
+    }
+}
+
+impl FromStr for Card {
+    type Err = ParseError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut split = s.splitn(2, ':');
+        let mut numbers_part = split.nth(1).required(s, "numbers part")?.split('|');
+
+        let winning_numbers = numbers_part
+            .next()
+            .required(s, "winning numbers")?
+            .split_ascii_whitespace()
+            .map(|n| parse_token(s, n, "winning number"))
+            .collect::<Result<_, _>>()?;
+
+        let numbers_you_have = numbers_part
+            .next()
+            .required(s, "numbers you have")?
+            .split_ascii_whitespace()
+            .map(|n| parse_token(s, n, "number you have"))
+            .collect::<Result<_, _>>()?;
+
+        Ok(Card {
+            winning_numbers,
+            numbers_you_have,
+        })
+    }
+}
+
+/// Parses the puzzle input.
+pub fn parse(input: &str) -> Result<Input> {
+    Ok(parse_lines(input)?)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const INPUT: &str = "
+        Card 1: 41 48 83 86 17 | 83 86  6 31 17  9 48 53
+        Card 2: 13 32 20 16 61 | 61 30 68 82 17 32 24 19
+        Card 3:  1 21 53 59 44 | 69 82 63 72 16 21 14  1
+        Card 4: 41 92 73 84 69 | 59 84 76 51 58  5 54 83
+        Card 5: 87 83 26 28 32 | 88 30 70 12 93 22 82 36
+        Card 6: 31 18 13 56 72 | 74 77 10 23 35 67 36 11";
+
+    fn as_input(s: &str) -> Result<Input> {
+        parse(
+            &s.split('\n')
+                .skip(1)
+                .map(|s| s.trim())
+                .collect::<Vec<_>>()
+                .join("\n"),
+        )
+    }
+
+    #[test]
+    fn test_part1() -> Result<()> {
+        assert_eq!(both_parts(&as_input(INPUT)?).0, 13);
+        Ok(())
+    }
+
+    #[test]
+    fn test_part2() -> Result<()> {
+        assert_eq!(both_parts(&as_input(INPUT)?).1, 30);
+        Ok(())
+    }
+
+    #[test]
+    fn test_parse_error() {
+        let input = INPUT.replace("83 86  6 31", "83 86  x 31");
+        let err = as_input(&input).unwrap_err();
+        let err = err.downcast_ref::<ParseError>().unwrap();
+        assert_eq!(err.message, "invalid number you have `x`");
+        assert_eq!((err.line, err.column, err.len), (1, 33, 1));
+    }
+}
@@ -0,0 +1,230 @@
+//! Day 5: If You Give A Seed A Fertilizer
+
+use anyhow::Result;
+
+use crate::interval::{Interval, IntervalSet};
+use crate::parse::{
+    blank_line, blocks, int, lines, newline, pair, parse_all, preceded, section, spaced, spaces,
+    tag, terminated, word, Parser,
+};
+use crate::Solution;
+
+pub type Input = Almanac;
+
+#[derive(Debug)]
+pub struct Almanac {
+    pub seeds: Vec<i64>,
+    pub maps: Vec<Map>,
+}
+
+#[derive(Debug)]
+pub struct Map {
+    pub source: String,
+    pub destination: String,
+    pub conversions: Vec<Conversion>,
+}
+
+/// Moves the values in `source` by `offset`.
+#[derive(Debug)]
+pub struct Conversion {
+    pub source: Interval,
+    pub offset: i64,
+}
+
+impl Almanac {
+    pub fn seeds_to_min_location(&self) -> i64 {
+        self.seeds
+            .iter()
+            .map(|&seed| self.maps.iter().fold(seed, |v, map| map.apply(v)))
+            .min()
+            .unwrap_or_default()
+    }
+
+    /*
+    // Initial brute force solution for part 2 that didn't have bugs
+    fn seed_ranges_to_min_location_brute(&self) -> Vec<i64> {
+        self.seeds
+            .chunks(2)
+            .flat_map(|chunk| {
+                let start = chunk[0];
+                let range = chunk[1];
+                println!("Attemping {start}:{range}");
+                (start..(start + range))
+                    .into_iter()
+                    .map(|seed| self.maps.iter().fold(seed, |v, map| map.apply(v)))
+                    .min()
+            })
+            .collect()
+    }
+    */
+
+    pub fn seed_ranges_to_min_location(&self) -> i64 {
+        let seeds = self
+            .seeds
+            .chunks(2)
+            .map(|chunk| Interval::with_len(chunk[0], chunk[1]))
+            .collect::<IntervalSet>();
+
+        self.maps
+            .iter()
+            .fold(seeds, |ranges, map| map.apply_ranges(&ranges))
+            .min()
+            .unwrap_or_default()
+    }
+}
+
+impl Map {
+    pub fn apply(&self, v: i64) -> i64 {
+        self.conversions
+            .iter()
+            .find(|conversion| conversion.source.contains(v))
+            .map_or(v, |conversion| v + conversion.offset)
+    }
+
+    pub fn apply_ranges(&self, ranges: &IntervalSet) -> IntervalSet {
+        let mut remaining = ranges.clone();
+        let mut converted = IntervalSet::new();
+
+        for Conversion { source, offset } in &self.conversions {
+            let (inside, outside) = remaining.split(source);
+            converted = converted.union(&inside.shift(*offset));
+            remaining = outside;
+        }
+
+        converted.union(&remaining)
+    }
+}
+
+pub fn part1(input: &Input) -> i64 {
+    input.seeds_to_min_location()
+}
+
+pub fn part2(input: &Input) -> i64 {
+    input.seed_ranges_to_min_location()
+}
+
+pub struct Day05;
+
+impl Solution for Day05 {
+    const DAY: u8 = 5;
+    type Input = Input;
+    type Output1 = i64;
+    type Output2 = i64;
+
+    fn parse(input: &str) -> Result<Input> {
+        parse(input)
+    }
+
+    fn part1(input: &Input) -> i64 {
+        part1(input)
+    }
+
+    fn part2(input: &Input) -> i64 {
+        part2(input)
+    }
+}
+
+/// Parses the puzzle input.
+pub fn parse(input: &str) -> Result<Input> {
+    let seeds = section("seeds:", spaced(int().named("seed")));
+
+    let header = pair(
+        terminated(word().named("source"), tag("-to-")),
+        terminated(word().named("destination"), tag(" map:")),
+    );
+    let conversion = pair(
+        int().named("destination range start"),
+        pair(
+            preceded(spaces(), int().named("source range start")),
+            preceded(spaces(), int().named("range length")),
+        ),
+    )
+    .map(
+        |(dst_range_start, (src_range_start, range_length)): (i64, _)| Conversion {
+            source: Interval::with_len(src_range_start, range_length),
+            offset: dst_range_start - src_range_start,
+        },
+    );
+    let map = pair(terminated(header, newline()), lines(conversion)).map(
+        |((source, destination), conversions)| Map {
+            source: source.to_string(),
+            destination: destination.to_string(),
+            conversions,
+        },
+    );
+
+    let (seeds, maps) = parse_all(input, pair(terminated(seeds, blank_line()), blocks(map)))?;
+    Ok(Almanac { seeds, maps })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::error::ParseError;
+
+    const INPUT: &str = "
+        seeds: 79 14 55 13
+
+        seed-to-soil map:
+        50 98 2
+        52 50 48
+        
+        soil-to-fertilizer map:
+        0 15 37
+        37 52 2
+        39 0 15
+        
+        fertilizer-to-water map:
+        49 53 8
+        0 11 42
+        42 0 7
+        57 7 4
+        
+        water-to-light map:
+        88 18 7
+        18 25 70
+        
+        light-to-temperature map:
+        45 77 23
+        81 45 19
+        68 64 13
+        
+        temperature-to-humidity map:
+        0 69 1
+        1 0 69
+        
+        humidity-to-location map:
+        60 56 37
+        56 93 4";
+
+    fn as_input(s: &str) -> Result<Input> {
+        parse(
+            &s.split('\n')
+                .skip(1)
+                .map(|s| s.trim())
+                .collect::<Vec<_>>()
+                .join("\n"),
+        )
+    }
+
+    #[test]
+    fn test_part1() -> Result<()> {
+        assert_eq!(part1(&as_input(INPUT)?), 35);
+        Ok(())
+    }
+
+    #[test]
+    fn test_part2() -> Result<()> {
+        assert_eq!(part2(&as_input(INPUT)?), 46);
+        Ok(())
+    }
+
+    #[test]
+    fn test_parse_error() {
+        let input = INPUT.replace("52 50 48", "52 5O 48");
+        let err = as_input(&input).unwrap_err();
+        let err = err.downcast_ref::<ParseError>().unwrap();
+        assert_eq!(err.message, "invalid source range start `5O`");
+        assert_eq!((err.line, err.column, err.len), (5, 4, 2));
+    }
+}
@@ -0,0 +1,147 @@
+//! Day 6: Wait For It
+
+use anyhow::{ensure, Result};
+
+use crate::math::isqrt;
+use crate::parse::{int, newline, pair, parse_all, section, spaced, terminated, Parser};
+use crate::Solution;
+
+pub type Input = Vec<Race>;
+
+#[derive(Debug)]
+pub struct Race {
+    pub time_ms: i64,
+    pub dist_ms: i64,
+}
+
+impl Race {
+    pub fn record_ways(&self) -> i64 {
+        let &Race { time_ms, dist_ms } = self;
+
+        let beats = |t: i64| t * (time_ms - t) > dist_ms;
+
+        // The first winning hold time is near the smaller root of t * (time - t) = dist.
+        let disc = time_ms * time_ms - 4 * dist_ms;
+        if disc < 0 {
+            return 0;
+        }
+        let mut t_until_above = ((time_ms - isqrt(disc)) / 2).max(0);
+        while t_until_above > 0 && beats(t_until_above - 1) {
+            t_until_above -= 1;
+        }
+        while t_until_above <= time_ms && !beats(t_until_above) {
+            t_until_above += 1;
+        }
+        // The distance peaks at half the time, so a record not beaten by then can't be beaten.
+        if t_until_above > time_ms / 2 {
+            return 0;
+        }
+
+        time_ms + 1 - (t_until_above * 2)
+    }
+}
+
+pub fn part1(input: &Input) -> i64 {
+    input.iter().map(Race::record_ways).product()
+}
+
+pub fn part2(input: &Input) -> i64 {
+    let mut time_buf = String::new();
+    let mut dist_buf = String::new();
+
+    for &Race { time_ms, dist_ms } in input {
+        time_buf.push_str(&format!("{time_ms}"));
+        dist_buf.push_str(&format!("{dist_ms}"));
+    }
+
+    let time_ms = time_buf.parse::<i64>().unwrap_or_default();
+    let dist_ms = dist_buf.parse::<i64>().unwrap_or_default();
+
+    Race { dist_ms, time_ms }.record_ways()
+}
+
+pub struct Day06;
+
+impl Solution for Day06 {
+    const DAY: u8 = 6;
+    type Input = Input;
+    type Output1 = i64;
+    type Output2 = i64;
+
+    fn parse(input: &str) -> Result<Input> {
+        parse(input)
+    }
+
+    fn part1(input: &Input) -> i64 {
+        part1(input)
+    }
+
+    fn part2(input: &Input) -> i64 {
+        part2(input)
+    }
+}
+
+/// Parses the puzzle input.
+pub fn parse(input: &str) -> Result<Input> {
+    let numbers = |what| spaced(int().named(what));
+    let (times, distances) = parse_all(
+        input,
+        pair(
+            terminated(section("Time:", numbers("time")), newline()),
+            section("Distance:", numbers("distance")),
+        ),
+    )?;
+    ensure!(
+        times.len() == distances.len(),
+        "{} times but {} distances",
+        times.len(),
+        distances.len()
+    );
+
+    Ok(times
+        .into_iter()
+        .zip(distances)
+        .map(|(time_ms, dist_ms)| Race { time_ms, dist_ms })
+        .collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const INPUT: &str = "
+        Time:      7  15   30
+        Distance:  9  40  200";
+
+    fn as_input(s: &str) -> Result<Input> {
+        parse(
+            &s.split('\n')
+                .skip(1)
+                .map(|s| s.trim())
+                .collect::<Vec<_>>()
+                .join("\n"),
+        )
+    }
+
+    #[test]
+    fn test_part1() -> Result<()> {
+        assert_eq!(part1(&as_input(INPUT)?), 288);
+        Ok(())
+    }
+
+    #[test]
+    fn test_part2() -> Result<()> {
+        assert_eq!(part2(&as_input(INPUT)?), 71503);
+        Ok(())
+    }
+
+    #[test]
+    fn test_unbeatable_record() {
+        let ways = |time_ms, dist_ms| Race { time_ms, dist_ms }.record_ways();
+        assert_eq!(ways(4, 4), 0);
+        assert_eq!(ways(5, 6), 0);
+        assert_eq!(ways(4, 3), 1);
+        assert_eq!(ways(0, 0), 0);
+        assert_eq!(ways(3, 100), 0);
+    }
+}
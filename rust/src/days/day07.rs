@@ -0,0 +1,208 @@
+//! Day 7: Camel Cards
+
+use std::collections::HashMap;
+use std::str::FromStr;
+
+use anyhow::Result;
+
+use crate::error::{parse_lines, ParseError, Required};
+use crate::Solution;
+
+pub type Input = Vec<Hand>;
+
+const CARDS: [char; 13] = [
+    'A', 'K', 'Q', 'J', 'T', '9', '8', '7', '6', '5', '4', '3', '2',
+];
+const JOKER_IDX: usize = 3;
+
+#[derive(Debug)]
+pub struct Hand {
+    pub cards: Vec<usize>,
+    pub bid: usize,
+}
+
+impl Hand {
+    pub fn get_type(&self, joker_pretend: bool) -> Type {
+        let mut freq = HashMap::<usize, i32>::new();
+        for &c in &self.cards {
+            *freq.entry(c).or_default() += 1;
+        }
+
+        let j_freq = if joker_pretend {
+            freq.remove(&JOKER_IDX)
+        } else {
+            None
+        };
+
+        let mut vals = freq.values().cloned().collect::<Vec<_>>();
+        vals.sort();
+        vals.reverse();
+
+        if let Some(j) = j_freq {
+            if vals.is_empty() {
+                vals.push(j);
+            } else {
+                vals[0] += j;
+            }
+        }
+
+        match vals[..] {
+            [5] => Type::FiveOfAKind,
+            [4, 1] => Type::FourOfAKind,
+            [3, 2] => Type::FullHouse,
+            [3, ..] => Type::ThreeOfAKind,
+            [2, 2, ..] => Type::TwoPair,
+            [2, ..] => Type::OnePair,
+            _ => Type::HighCard,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+pub enum Type {
+    FiveOfAKind,
+    FourOfAKind,
+    FullHouse,
+    ThreeOfAKind,
+    TwoPair,
+    OnePair,
+    HighCard,
+}
+
+impl Type {
+    fn idx(&self) -> isize {
+        *self as isize
+    }
+}
+
+fn total_winnings(hands: &Vec<Hand>, joker_pretend: bool) -> usize {
+    let mut hand_type = Vec::with_capacity(hands.len());
+    for hand in hands {
+        let typ = hand.get_type(joker_pretend);
+        let cards = hand
+            .cards
+            .iter()
+            .map(|&c| {
+                if joker_pretend && c == JOKER_IDX {
+                    CARDS.len()
+                } else {
+                    c
+                }
+            })
+            .collect::<Vec<_>>();
+
+        hand_type.push((hand, typ, cards));
+    }
+
+    hand_type.sort_by(|a, b| a.1.idx().cmp(&b.1.idx()).then(a.2.cmp(&b.2)));
+
+    let num_hands = hand_type.len();
+    let mut res = 0;
+    for (i, (hand, _, _)) in hand_type.into_iter().enumerate() {
+        let rank = num_hands - i;
+        res += rank * hand.bid;
+    }
+
+    res
+}
+
+pub fn part1(input: &Input) -> usize {
+    total_winnings(input, false)
+}
+
+pub fn part2(input: &Input) -> usize {
+    total_winnings(input, true)
+}
+
+pub struct Day07;
+
+impl Solution for Day07 {
+    const DAY: u8 = 7;
+    type Input = Input;
+    type Output1 = usize;
+    type Output2 = usize;
+
+    fn parse(input: &str) -> Result<Input> {
+        parse(input)
+    }
+
+    fn part1(input: &Input) -> usize {
+        part1(input)
+    }
+
+    fn part2(input: &Input) -> usize {
+        part2(input)
+    }
+}
+
+impl FromStr for Hand {
+    type Err = ParseError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut split = s.split(' ');
+        let cards_part = split.next().required(s, "cards")?;
+        let cards = cards_part
+            .char_indices()
+            .map(|(i, c)| {
+                card_idx(c).ok_or_else(|| {
+                    let card = &cards_part[i..(i + c.len_utf8())];
+                    ParseError::new(s, card, format!("no card for `{c}`"))
+                })
+            })
+            .collect::<Result<_, _>>()?;
+        let bid = split.next().parse_required(s, "bid")?;
+
+        Ok(Hand { cards, bid })
+    }
+}
+
+fn card_idx(c: char) -> Option<usize> {
+    CARDS.iter().position(|&b| c == b)
+}
+
+/// Parses the puzzle input.
+pub fn parse(input: &str) -> Result<Input> {
+    Ok(parse_lines(input)?)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const INPUT: &str = "
+        32T3K 765
+        T55J5 684
+        KK677 28
+        KTJJT 220
+        QQQJA 483";
+
+    fn as_input(s: &str) -> Result<Input> {
+        parse(
+            &s.split('\n')
+                .skip(1)
+                .map(|s| s.trim())
+                .collect::<Vec<_>>()
+                .join("\n"),
+        )
+    }
+
+    #[test]
+    fn test_part1() -> Result<()> {
+        assert_eq!(part1(&as_input(INPUT)?), 6440);
+        Ok(())
+    }
+
+    #[test]
+    fn test_part2() -> Result<()> {
+        assert_eq!(part2(&as_input(INPUT)?), 5905);
+        Ok(())
+    }
+
+    #[test]
+    fn test_parse_error() {
+        let input = INPUT.replace("KK677", "KK6X7");
+        let err = as_input(&input).unwrap_err();
+        let err = err.downcast_ref::<ParseError>().unwrap();
+        assert_eq!(err.message, "no card for `X`");
+        assert_eq!((err.line, err.column, err.len), (3, 4, 1));
+    }
+}
@@ -0,0 +1,164 @@
+//! Day 8: Haunted Wasteland
+
+use std::collections::HashMap;
+
+use anyhow::Result;
+
+use crate::math::lcm_all;
+use crate::parse::{
+    blank_line, delimited, key_value, lines, pair, parse_all, tag, take_while1, terminated, word,
+    Parser,
+};
+use crate::Solution;
+
+pub type Input = Map;
+
+#[derive(Debug)]
+pub struct Map {
+    pub instructions: Vec<char>,
+    pub connections: HashMap<String, (String, String)>,
+}
+
+pub fn part1(input: &Input) -> i32 {
+    let Map {
+        instructions,
+        connections,
+    } = input;
+
+    let mut steps = 0;
+    let mut curr = &"AAA".to_string();
+    for s in 0.. {
+        if curr == "ZZZ" {
+            break;
+        }
+        let i_idx = s % instructions.len();
+
+        let dir = instructions[i_idx];
+        let dirs = &connections[curr];
+
+        match dir {
+            'L' => curr = &dirs.0,
+            'R' => curr = &dirs.1,
+            _ => unreachable!(),
+        }
+        steps += 1;
+    }
+    steps
+}
+
+pub fn part2(input: &Input) -> u64 {
+    let Map {
+        instructions,
+        connections,
+    } = input;
+
+    let currs = connections
+        .keys()
+        .filter(|s| s.ends_with('A'))
+        .collect::<Vec<_>>();
+    let mut all_steps = vec![0_u64; currs.len()];
+
+    for i in 0..currs.len() {
+        let mut steps = 0;
+        let mut curr = currs[i];
+        for s in 0.. {
+            if curr.ends_with('Z') {
+                break;
+            }
+            let i_idx = s % instructions.len();
+            let dir = instructions[i_idx];
+
+            let dirs = &connections[curr];
+
+            match dir {
+                'L' => curr = &dirs.0,
+                'R' => curr = &dirs.1,
+                _ => unreachable!(),
+            }
+            steps += 1;
+        }
+        all_steps[i] = steps;
+    }
+
+    lcm_all(all_steps).expect("Cycle lengths overflow")
+}
+
+pub struct Day08;
+
+impl Solution for Day08 {
+    const DAY: u8 = 8;
+    type Input = Input;
+    type Output1 = i32;
+    type Output2 = u64;
+
+    fn parse(input: &str) -> Result<Input> {
+        parse(input)
+    }
+
+    fn part1(input: &Input) -> i32 {
+        part1(input)
+    }
+
+    fn part2(input: &Input) -> u64 {
+        part2(input)
+    }
+}
+
+/// Parses the puzzle input.
+pub fn parse(input: &str) -> Result<Input> {
+    let instructions = take_while1(|c| c == 'L' || c == 'R', "instructions");
+    let node = || word().named("node").map(str::to_string);
+    let connection = key_value(
+        node(),
+        "=",
+        delimited(
+            tag("("),
+            pair(terminated(node(), tag(", ")), node()),
+            tag(")"),
+        ),
+    );
+
+    let (instructions, connections) = parse_all(
+        input,
+        pair(terminated(instructions, blank_line()), lines(connection)),
+    )?;
+
+    Ok(Map {
+        instructions: instructions.chars().collect(),
+        connections: connections.into_iter().collect(),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const INPUT: &str = "
+    LLR
+
+    AAA = (BBB, BBB)
+    BBB = (AAA, ZZZ)
+    ZZZ = (ZZZ, ZZZ)";
+
+    fn as_input(s: &str) -> Result<Input> {
+        parse(
+            &s.split('\n')
+                .skip(1)
+                .map(|s| s.trim())
+                .collect::<Vec<_>>()
+                .join("\n"),
+        )
+    }
+
+    #[test]
+    fn test_part1() -> Result<()> {
+        assert_eq!(part1(&as_input(INPUT)?), 6);
+        Ok(())
+    }
+
+    #[test]
+    fn test_part2() -> Result<()> {
+        assert_eq!(part2(&as_input(INPUT)?), 6);
+        Ok(())
+    }
+}
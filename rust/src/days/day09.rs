@@ -0,0 +1,160 @@
+//! Day 9: Mirage Maintenance
+
+use std::str::FromStr;
+
+use anyhow::Result;
+
+use crate::error::{parse_lines, parse_token, ParseError};
+use crate::Solution;
+
+pub type Input = Vec<History>;
+
+#[derive(Debug)]
+pub struct History {
+    pub values: Vec<i32>,
+}
+
+/// The differences between the values, then between those differences and so on.
+fn diffs(values: &[i32]) -> Vec<Vec<i32>> {
+    let mut diffs = vec![];
+
+    for w in values.windows(2) {
+        let mut d = w[1] - w[0];
+        let mut idx = 0;
+        let mut cont = true;
+        let n_diffs = diffs.len();
+
+        while cont {
+            let diff = if let Some(diff) = diffs.get_mut(idx) {
+                diff
+            } else {
+                diffs.push(vec![]);
+                diffs.get_mut(idx).unwrap()
+            };
+
+            diff.push(d);
+
+            d = if diff.len() >= 2 {
+                let a = diff[diff.len() - 1];
+                let b = diff[diff.len() - 2];
+                let diff = a - b;
+                cont = idx < n_diffs;
+                diff
+            } else {
+                cont = false;
+                0
+            };
+
+            idx += 1;
+        }
+    }
+    diffs
+}
+
+pub fn part1(input: &Input) -> i32 {
+    let mut p1 = 0;
+    for History { values } in input {
+        let diffs = diffs(values);
+        let mut last = *diffs[diffs.len() - 1].last().unwrap();
+        for i in (0..(diffs.len() - 1)).rev() {
+            let next = diffs[i].last().unwrap() + last;
+            last = next;
+        }
+        let next_val = values.last().unwrap() + last;
+        p1 += next_val;
+    }
+    p1
+}
+
+pub fn part2(input: &Input) -> i32 {
+    let mut p2 = 0;
+    for History { values } in input {
+        let diffs = diffs(values);
+        let mut last = *diffs[diffs.len() - 1].first().unwrap();
+        for i in (0..(diffs.len() - 1)).rev() {
+            let next = diffs[i].first().unwrap() - last;
+            last = next;
+        }
+        let next_val = values.first().unwrap() - last;
+        p2 += next_val;
+    }
+    p2
+}
+
+pub struct Day09;
+
+impl Solution for Day09 {
+    const DAY: u8 = 9;
+    type Input = Input;
+    type Output1 = i32;
+    type Output2 = i32;
+
+    fn parse(input: &str) -> Result<Input> {
+        parse(input)
+    }
+
+    fn part1(input: &Input) -> i32 {
+        part1(input)
+    }
+
+    fn part2(input: &Input) -> i32 {
+        part2(input)
+    }
+}
+
+impl FromStr for History {
+    type Err = ParseError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let values = s
+            .split(' ')
+            .map(|v| parse_token(s, v, "value"))
+            .collect::<Result<_, _>>()?;
+        Ok(History { values })
+    }
+}
+
+/// Parses the puzzle input.
+pub fn parse(input: &str) -> Result<Input> {
+    Ok(parse_lines(input)?)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const INPUT: &str = "
+        0 3 6 9 12 15
+        1 3 6 10 15 21
+        10 13 16 21 30 45";
+
+    fn as_input(s: &str) -> Result<Input> {
+        parse(
+            &s.split('\n')
+                .skip(1)
+                .map(|s| s.trim())
+                .collect::<Vec<_>>()
+                .join("\n"),
+        )
+    }
+
+    #[test]
+    fn test_part1() -> Result<()> {
+        assert_eq!(part1(&as_input(INPUT)?), 114);
+        Ok(())
+    }
+
+    #[test]
+    fn test_part2() -> Result<()> {
+        assert_eq!(part2(&as_input(INPUT)?), 2);
+        Ok(())
+    }
+
+    #[test]
+    fn test_parse_error() {
+        let input = INPUT.replace("1 3 6 10", "1 3 six 10");
+        let err = as_input(&input).unwrap_err();
+        let err = err.downcast_ref::<ParseError>().unwrap();
+        assert_eq!(err.message, "invalid value `six`");
+        assert_eq!((err.line, err.column, err.len), (2, 5, 3));
+    }
+}
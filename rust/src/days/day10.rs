@@ -0,0 +1,313 @@
+//! Day 10: Pipe Maze
+
+use std::collections::{HashMap, HashSet};
+
+use anyhow::Result;
+
+use crate::geometry::{self, Dir};
+use crate::graph;
+use crate::grid::Grid;
+use crate::viz::{Color, Frame, Visualizer};
+use crate::Solution;
+
+pub type Input = Diagram;
+
+#[derive(Debug)]
+pub struct Diagram {
+    pub pipes: Grid<char>,
+}
+
+type Pos = geometry::Pos<i32>;
+
+impl Diagram {
+    pub fn start_pos(&self) -> Pos {
+        Pos::from_grid(self.pipes.find(|&c| c == 'S').unwrap())
+    }
+
+    fn start_adjacent(&self, start_pos: Pos) -> (Pos, Pos) {
+        let adj = Dir::ALL
+            .into_iter()
+            .filter(|&dir| {
+                let p = start_pos.step(dir);
+                self.within(p) && pipe_dirs(self.pipes[p]).contains(&dir.reverse())
+            })
+            .map(|dir| start_pos.step(dir))
+            .collect::<Vec<_>>();
+
+        assert_eq!(2, adj.len());
+        (adj[0], adj[1])
+    }
+
+    fn connections_at(&self, p: Pos) -> (Pos, Pos) {
+        let &[a, b] = pipe_dirs(self.pipes[p]) else {
+            unreachable!()
+        };
+        (p.step(a), p.step(b))
+    }
+
+    fn within(&self, p: Pos) -> bool {
+        self.pipes.get_pos(p).is_some()
+    }
+
+    /// The loop drawn with box characters in cyan from the start in red, and the tiles it
+    /// encloses in green.
+    pub fn frame(&self) -> Frame {
+        let Survey { dists, enclosed } = survey(self);
+        let mut frame = Frame::new(
+            "The loop in cyan from the start in red, enclosed tiles in green",
+            &self.pipes,
+        );
+
+        for (pos, &pipe) in self.pipes.iter() {
+            let p = Pos::from_grid(pos);
+            if pipe == 'S' {
+                frame.paint(pos, Color::Red);
+            } else if dists.contains_key(&p) {
+                let c = match pipe {
+                    '|' => '│',
+                    '-' => '─',
+                    'L' => '└',
+                    'J' => '┘',
+                    '7' => '┐',
+                    'F' => '┌',
+                    c => c,
+                };
+                frame.set(pos, c);
+                frame.paint(pos, Color::Cyan);
+            } else if enclosed.contains(&p) {
+                frame.set(pos, '•');
+                frame.paint(pos, Color::Green);
+            }
+        }
+        frame.paint_rest(Color::Dim);
+        frame
+    }
+}
+
+/// The two directions a pipe leads to.
+fn pipe_dirs(pipe: char) -> &'static [Dir] {
+    match pipe {
+        '|' => &[Dir::Up, Dir::Down],
+        '-' => &[Dir::Left, Dir::Right],
+        'L' => &[Dir::Up, Dir::Right],
+        'J' => &[Dir::Up, Dir::Left],
+        '7' => &[Dir::Down, Dir::Left],
+        'F' => &[Dir::Down, Dir::Right],
+        _ => &[],
+    }
+}
+
+/// The tiles on the left hand side when walking through `pipe` at `p` in direction `dir`.
+fn left_of(dir: Dir, p: Pos, pipe: char) -> Vec<Pos> {
+    let exit = pipe_dirs(pipe)
+        .iter()
+        .find(|&&d| d != dir.reverse())
+        .copied()
+        .unwrap();
+    let left = p.step(dir.turn_left());
+
+    if exit == dir {
+        vec![left]
+    } else if exit == dir.turn_right() {
+        vec![left, left.step(dir), p.step(dir)]
+    } else {
+        vec![]
+    }
+}
+
+/// The loop with the distance of each pipe from the start, and the tiles it encloses.
+struct Survey {
+    dists: HashMap<Pos, usize>,
+    enclosed: HashSet<Pos>,
+}
+
+fn survey(input: &Input) -> Survey {
+    let dists = loop_dists(input);
+    let enclosed = enclosed(input, &dists);
+    Survey { dists, enclosed }
+}
+
+/// The distance of each pipe on the loop from the start.
+fn loop_dists(input: &Input) -> HashMap<Pos, usize> {
+    let start = input.start_pos();
+    let start_adj = input.start_adjacent(start);
+
+    graph::bfs([start], |pos| {
+        let (a, b) = if pos == start {
+            start_adj
+        } else {
+            input.connections_at(pos)
+        };
+        [a, b]
+    })
+    .dist
+}
+
+/// The tiles enclosed by the loop with the pipes in `dists`.
+fn enclosed(input: &Input, dists: &HashMap<Pos, usize>) -> HashSet<Pos> {
+    let start = input.start_pos();
+    let start_adj = input.start_adjacent(start);
+
+    [start_adj.0, start_adj.1]
+        .into_iter()
+        .map(|start_adj| {
+            let mut prev = start;
+            let mut curr = start_adj;
+            let mut enclosed = HashSet::new();
+
+            while curr != start {
+                let dir = Dir::from_delta(curr - prev).unwrap();
+
+                for l in left_of(dir, curr, input.pipes[curr]) {
+                    if !dists.contains_key(&l) {
+                        enclosed.insert(l);
+                    }
+                }
+
+                let (a, b) = input.connections_at(curr);
+                let next = if a == prev { b } else { a };
+
+                prev = curr;
+                curr = next;
+            }
+
+            // Expand
+            graph::flood_fill(enclosed, |p| {
+                p.neighbours4()
+                    .filter(|adj| input.within(*adj) && !dists.contains_key(adj))
+            })
+        })
+        .min_by_key(HashSet::len)
+        .unwrap_or_default()
+}
+
+pub fn part1(input: &Input) -> usize {
+    loop_dists(input)
+        .values()
+        .max()
+        .cloned()
+        .unwrap_or_default()
+}
+
+pub fn part2(input: &Input) -> usize {
+    enclosed(input, &loop_dists(input)).len()
+}
+
+pub struct Day10;
+
+impl Solution for Day10 {
+    const DAY: u8 = 10;
+    type Input = Input;
+    type Output1 = usize;
+    type Output2 = usize;
+
+    fn parse(input: &str) -> Result<Input> {
+        parse(input)
+    }
+
+    fn part1(input: &Input) -> usize {
+        part1(input)
+    }
+
+    fn part2(input: &Input) -> usize {
+        part2(input)
+    }
+
+    fn visualize(input: &Input, viz: &mut Visualizer) -> Result<()> {
+        viz.show(&input.frame())
+    }
+}
+
+/// Parses the puzzle input.
+pub fn parse(input: &str) -> Result<Input> {
+    let pipes = input.parse()?;
+    Ok(Diagram { pipes })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const INPUT: &str = "
+        ..F7.
+        .FJ|.
+        SJ.L7
+        |F--J
+        LJ...";
+
+    const INPUT2: &str = "
+        ...........
+        .S-------7.
+        .|F-----7|.
+        .||.....||.
+        .||.....||.
+        .|L-7.F-J|.
+        .|..|.|..|.
+        .L--J.L--J.
+        ...........";
+
+    const INPUT3: &str = "
+        .F----7F7F7F7F-7....
+        .|F--7||||||||FJ....
+        .||.FJ||||||||L7....
+        FJL7L7LJLJ||LJ.L-7..
+        L--J.L7...LJS7F-7L7.
+        ....F-J..F7FJ|L7L7L7
+        ....L7.F7||L7|.L7L7|
+        .....|FJLJ|FJ|F7|.LJ
+        ....FJL-7.||.||||...
+        ....L---J.LJ.LJLJ...";
+
+    const INPUT4: &str = "
+        FF7FSF7F7F7F7F7F---7
+        L|LJ||||||||||||F--J
+        FL-7LJLJ||||||LJL-77
+        F--JF--7||LJLJ7F7FJ-
+        L---JF-JLJ.||-FJLJJ7
+        |F|F-JF---7F7-L7L|7|
+        |FFJF7L7F-JF7|JL---7
+        7-L-JL7||F7|L7F-7F7|
+        L.L7LFJ|||||FJL7||LJ
+        L7JLJL-JLJLJL--JLJ.L";
+
+    fn as_input(s: &str) -> Result<Input> {
+        parse(
+            &s.split('\n')
+                .skip(1)
+                .map(|s| s.trim())
+                .collect::<Vec<_>>()
+                .join("\n"),
+        )
+    }
+
+    #[test]
+    fn test_part1() -> Result<()> {
+        assert_eq!(part1(&as_input(INPUT)?), 8);
+        Ok(())
+    }
+
+    #[test]
+    fn test_part2() -> Result<()> {
+        assert_eq!(part2(&as_input(INPUT2)?), 4);
+        assert_eq!(part2(&as_input(INPUT3)?), 8);
+        assert_eq!(part2(&as_input(INPUT4)?), 10);
+        Ok(())
+    }
+
+    #[test]
+    fn test_frame() -> Result<()> {
+        let frame = as_input(INPUT2)?.frame();
+        assert_eq!(
+            frame.plain().lines().skip(1).take(4).collect::<Vec<_>>(),
+            ["...........", ".S───────┐.", ".│┌─────┐│.", ".││.....││.",]
+        );
+        assert!(frame.plain().contains("\n.│••│.│••│.\n"));
+
+        let color = |x, y| frame.cells[(x, y)].color;
+        assert_eq!(color(1, 1), Some(Color::Red));
+        assert_eq!(color(2, 1), Some(Color::Cyan));
+        assert_eq!(color(2, 6), Some(Color::Green));
+        assert_eq!(color(3, 3), Some(Color::Dim));
+        Ok(())
+    }
+}
@@ -0,0 +1,176 @@
+//! Day 11: Cosmic Expansion
+
+use anyhow::Result;
+
+use crate::geometry::Pos;
+use crate::Solution;
+
+pub type Input = Space;
+
+#[derive(Debug, Clone)]
+pub struct Space {
+    pub galaxies: Vec<Pos>,
+}
+
+impl Space {
+    pub fn expand(&mut self, amount: i64) {
+        let step = amount - 1;
+
+        let mut max_x = self.galaxies.iter().map(|p| p.x).max().unwrap_or_default();
+        let mut x = 0;
+
+        while x < max_x {
+            let found = self.galaxies.iter().any(|g| g.x == x);
+            if found {
+                x += 1;
+                continue;
+            }
+
+            let mut any = false;
+            for g in self.galaxies.iter_mut() {
+                if g.x > x {
+                    g.x += step;
+                    any = true;
+                }
+            }
+
+            if any {
+                x += step;
+                max_x += step;
+            }
+            x += 1;
+        }
+
+        let mut max_y = self.galaxies.iter().map(|p| p.y).max().unwrap_or_default();
+        let mut y = 0;
+
+        while y < max_y {
+            let found = self.galaxies.iter().any(|g| g.y == y);
+            if found {
+                y += 1;
+                continue;
+            }
+
+            let mut any = false;
+            for g in self.galaxies.iter_mut() {
+                if g.y > y {
+                    g.y += step;
+                    any = true;
+                }
+            }
+
+            if any {
+                y += step;
+                max_y += step;
+            }
+            y += 1;
+        }
+    }
+
+    /// Every pair of galaxies once, as indices into `galaxies`.
+    fn galaxy_pairs(&self) -> impl Iterator<Item = (usize, usize)> {
+        let n = self.galaxies.len();
+        (0..n).flat_map(move |a| (a + 1..n).map(move |b| (a, b)))
+    }
+}
+
+pub fn part1(input: &Input) -> i64 {
+    let mut space = input.clone();
+    space.expand(2);
+
+    space
+        .galaxy_pairs()
+        .map(|(a, b)| space.galaxies[a].manhattan(&space.galaxies[b]))
+        .sum()
+}
+
+pub fn part2(input: &Input) -> i64 {
+    let mut space = input.clone();
+
+    #[cfg(not(test))]
+    space.expand(1000000);
+    #[cfg(test)]
+    space.expand(100);
+
+    space
+        .galaxy_pairs()
+        .map(|(a, b)| space.galaxies[a].manhattan(&space.galaxies[b]))
+        .sum()
+}
+
+pub struct Day11;
+
+impl Solution for Day11 {
+    const DAY: u8 = 11;
+    type Input = Input;
+    type Output1 = i64;
+    type Output2 = i64;
+
+    fn parse(input: &str) -> Result<Input> {
+        parse(input)
+    }
+
+    fn part1(input: &Input) -> i64 {
+        part1(input)
+    }
+
+    fn part2(input: &Input) -> i64 {
+        part2(input)
+    }
+}
+
+/// Parses the puzzle input.
+pub fn parse(input: &str) -> Result<Input> {
+    let lines = input.lines();
+
+    let mut galaxies = vec![];
+
+    for (y, line) in lines.enumerate() {
+        for (x, c) in line.chars().enumerate() {
+            if c == '#' {
+                galaxies.push(Pos::from_grid((x, y)));
+            }
+        }
+    }
+
+    Ok(Space { galaxies })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const INPUT: &str = "
+        ...#......
+        .......#..
+        #.........
+        ..........
+        ......#...
+        .#........
+        .........#
+        ..........
+        .......#..
+        #...#.....";
+
+    fn as_input(s: &str) -> Result<Input> {
+        parse(
+            &s.split('\n')
+                .skip(1)
+                .map(|s| s.trim())
+                .collect::<Vec<_>>()
+                .join("\n"),
+        )
+    }
+
+    #[test]
+    fn test_part1() -> Result<()> {
+        assert_eq!(part1(&as_input(INPUT)?), 374);
+        Ok(())
+    }
+
+    #[test]
+    fn test_part2() -> Result<()> {
+        assert_eq!(part2(&as_input(INPUT)?), 8410);
+        Ok(())
+    }
+}
@@ -0,0 +1,197 @@
+//! Day 12: Hot Springs
+
+use std::str::FromStr;
+
+use anyhow::Result;
+
+use crate::error::{parse_lines, parse_token, ParseError, Required};
+use crate::memo::Memo;
+use crate::Solution;
+
+pub type Input = Vec<SpringRow>;
+
+#[derive(Debug)]
+pub struct SpringRow {
+    pub condition_record: Vec<char>,
+    pub damage_groups: Vec<usize>,
+}
+
+impl SpringRow {
+    pub fn five_copies(&self) -> Self {
+        let mut condition_record = vec![];
+        let mut damage_groups = vec![];
+
+        for i in 0..5 {
+            if i > 0 {
+                condition_record.push('?');
+            }
+            condition_record.append(&mut self.condition_record.clone());
+            damage_groups.append(&mut self.damage_groups.clone());
+        }
+
+        Self {
+            condition_record,
+            damage_groups,
+        }
+    }
+
+    pub fn possible_arrangements(&self) -> i64 {
+        // There's nothing to place groups in, or no groups to place, and the memo would be empty.
+        if self.condition_record.is_empty() || self.damage_groups.is_empty() {
+            return 0;
+        }
+        let shape = (self.condition_record.len(), self.damage_groups.len());
+        Memo::dense(shape).solve((0, 0), &|recurse, (record_offset, group_offset)| {
+            self.possible_arrangements_group(record_offset, group_offset, recurse)
+        })
+    }
+
+    fn possible_arrangements_group(
+        &self,
+        record_offset: usize,
+        group_offset: usize,
+        recurse: &mut dyn FnMut((usize, usize)) -> i64,
+    ) -> i64 {
+        let records = &self.condition_record[record_offset..];
+        let groups = &self.damage_groups[group_offset..];
+
+        let group_len = groups[0];
+        let min_groups_len_rem = groups.iter().sum::<usize>() + groups.len() - 1;
+
+        let mut possible = 0;
+        for i in 0..(records.len() - (group_len - 1)) {
+            if records.len() - i < min_groups_len_rem {
+                break;
+            }
+            if i > 0 && records[i - 1] == '#' {
+                break;
+            }
+
+            let g = &records[i..(i + group_len)];
+            if g.iter().all(|&c| c == '#' || c == '?')
+                && ((i + group_len) >= records.len()
+                    || ['.', '?'].into_iter().any(|c| records[i + group_len] == c))
+            {
+                let next_offset = record_offset + group_len + i + 1;
+                if group_offset < self.damage_groups.len() - 1 {
+                    if next_offset < self.condition_record.len() {
+                        possible += recurse((next_offset, group_offset + 1));
+                    }
+                } else {
+                    let is_possible = !records.iter().skip(i + group_len).any(|&c| c == '#');
+
+                    if is_possible {
+                        possible += 1;
+                    }
+                }
+            }
+        }
+
+        possible
+    }
+}
+
+pub fn part1(input: &Input) -> i64 {
+    input.iter().map(|row| row.possible_arrangements()).sum()
+}
+
+pub fn part2(input: &Input) -> i64 {
+    input
+        .iter()
+        .map(|row| row.five_copies().possible_arrangements())
+        .sum()
+}
+
+pub struct Day12;
+
+impl Solution for Day12 {
+    const DAY: u8 = 12;
+    type Input = Input;
+    type Output1 = i64;
+    type Output2 = i64;
+
+    fn parse(input: &str) -> Result<Input> {
+        parse(input)
+    }
+
+    fn part1(input: &Input) -> i64 {
+        part1(input)
+    }
+
+    fn part2(input: &Input) -> i64 {
+        part2(input)
+    }
+}
+
+impl FromStr for SpringRow {
+    type Err = ParseError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut split = s.split(' ');
+        let condition_record = split
+            .next()
+            .required(s, "condition record")?
+            .chars()
+            .collect::<Vec<_>>();
+        let damage_groups = split
+            .next()
+            .required(s, "groups")?
+            .split(',')
+            .map(|g| parse_token(s, g, "group length"))
+            .collect::<Result<_, _>>()?;
+
+        Ok(SpringRow {
+            condition_record,
+            damage_groups,
+        })
+    }
+}
+
+/// Parses the puzzle input.
+pub fn parse(input: &str) -> Result<Input> {
+    Ok(parse_lines(input)?)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const INPUT: &str = "
+        ???.### 1,1,3
+        .??..??...?##. 1,1,3
+        ?#?#?#?#?#?#?#? 1,3,1,6
+        ????.#...#... 4,1,1
+        ????.######..#####. 1,6,5
+        ?###???????? 3,2,1";
+
+    fn as_input(s: &str) -> Result<Input> {
+        parse(
+            &s.split('\n')
+                .skip(1)
+                .map(|s| s.trim())
+                .collect::<Vec<_>>()
+                .join("\n"),
+        )
+    }
+
+    #[test]
+    fn test_part1() -> Result<()> {
+        assert_eq!(part1(&as_input(INPUT)?), 21);
+        assert_eq!(part1(&parse(" 1")?), 0);
+        Ok(())
+    }
+
+    #[test]
+    fn test_part2() -> Result<()> {
+        assert_eq!(part2(&as_input(INPUT)?), 525152);
+        Ok(())
+    }
+
+    #[test]
+    fn test_parse_error() {
+        let input = INPUT.replace("4,1,1", "4,one,1");
+        let err = as_input(&input).unwrap_err();
+        let err = err.downcast_ref::<ParseError>().unwrap();
+        assert_eq!(err.message, "invalid group length `one`");
+        assert_eq!((err.line, err.column, err.len), (4, 17, 3));
+    }
+}
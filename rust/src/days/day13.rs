@@ -0,0 +1,203 @@
+//! Day 13: Point of Incidence
+
+use anyhow::Result;
+
+use crate::grid::Grid;
+use crate::viz::{Color, Frame, Visualizer};
+use crate::Solution;
+
+pub type Input = Vec<Pattern>;
+
+#[derive(Debug)]
+pub struct Pattern {
+    pub grid: Grid<char>,
+}
+
+impl Pattern {
+    pub fn vertical_reflection(&self) -> Option<usize> {
+        reflection(&self.grid, 0)
+    }
+
+    pub fn horizontal_reflection(&self) -> Option<usize> {
+        reflection(&self.grid.transpose(), 0)
+    }
+
+    pub fn vertical_reflection_smudge(&self) -> Option<usize> {
+        reflection(&self.grid, 1)
+    }
+
+    pub fn horizontal_reflection_smudge(&self) -> Option<usize> {
+        reflection(&self.grid.transpose(), 1)
+    }
+
+    /// The pattern with the cells next to the mirror line in cyan, and those next to the
+    /// mirror line once the smudge is fixed in yellow.
+    pub fn frame(&self, title: &str) -> Frame {
+        let mut frame = Frame::new(title, &self.grid);
+
+        for (smudges, color) in [(1, Color::Yellow), (0, Color::Cyan)] {
+            // Like the puzzle, a vertical mirror wins over a horizontal one.
+            let vertical = reflection(&self.grid, smudges);
+            let horizontal = vertical
+                .is_none()
+                .then(|| reflection(&self.grid.transpose(), smudges))
+                .flatten();
+
+            for (x, y) in self.grid.positions() {
+                let next_to =
+                    |line: Option<usize>, i: usize| line.is_some_and(|l| i + 1 == l || i == l);
+                if next_to(vertical, x) || next_to(horizontal, y) {
+                    frame.paint((x, y), color);
+                }
+            }
+        }
+        frame.paint_rest(Color::Dim);
+        frame
+    }
+}
+
+/// The number of columns left of a vertical mirror line, where exactly `smudges` cells differ
+/// from their reflection.
+fn reflection(grid: &Grid<char>, smudges: usize) -> Option<usize> {
+    (1..grid.width()).find(|&x| {
+        let mut diff = 0;
+        for row in grid.rows() {
+            let lhs = &row[0..x];
+            let rhs = &row[x..];
+
+            diff += lhs.iter().rev().zip(rhs).filter(|(a, b)| a != b).count();
+            if diff > smudges {
+                return false;
+            }
+        }
+        diff == smudges
+    })
+}
+
+pub fn part1(input: &Input) -> usize {
+    input.iter().fold(0, |acc, pattern| {
+        if let Some(vert) = pattern.vertical_reflection() {
+            return acc + vert;
+        }
+        if let Some(hor) = pattern.horizontal_reflection() {
+            return acc + 100 * hor;
+        }
+        acc
+    })
+}
+
+pub fn part2(input: &Input) -> usize {
+    input.iter().fold(0, |acc, pattern| {
+        if let Some(vert) = pattern.vertical_reflection_smudge() {
+            return acc + vert;
+        }
+        if let Some(hor) = pattern.horizontal_reflection_smudge() {
+            return acc + 100 * hor;
+        }
+        acc
+    })
+}
+
+pub struct Day13;
+
+impl Solution for Day13 {
+    const DAY: u8 = 13;
+    type Input = Input;
+    type Output1 = usize;
+    type Output2 = usize;
+
+    fn parse(input: &str) -> Result<Input> {
+        parse(input)
+    }
+
+    fn part1(input: &Input) -> usize {
+        part1(input)
+    }
+
+    fn part2(input: &Input) -> usize {
+        part2(input)
+    }
+
+    fn visualize(input: &Input, viz: &mut Visualizer) -> Result<()> {
+        for (i, pattern) in input.iter().enumerate() {
+            let title = format!(
+                "Pattern {}: mirror in cyan, mirror without smudge in yellow",
+                i + 1
+            );
+            viz.show(&pattern.frame(&title))?;
+        }
+        Ok(())
+    }
+}
+
+/// Parses the puzzle input.
+pub fn parse(input: &str) -> Result<Input> {
+    input
+        .split("\n\n")
+        .map(|block| {
+            Ok(Pattern {
+                grid: block.parse()?,
+            })
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const INPUT: &str = "
+        #.##..##.
+        ..#.##.#.
+        ##......#
+        ##......#
+        ..#.##.#.
+        ..##..##.
+        #.#.##.#.
+        
+        #...##..#
+        #....#..#
+        ..##..###
+        #####.##.
+        #####.##.
+        ..##..###
+        #....#..#";
+
+    fn as_input(s: &str) -> Result<Input> {
+        parse(
+            &s.split('\n')
+                .skip(1)
+                .map(|s| s.trim())
+                .collect::<Vec<_>>()
+                .join("\n"),
+        )
+    }
+
+    #[test]
+    fn test_part1() -> Result<()> {
+        assert_eq!(part1(&as_input(INPUT)?), 405);
+        Ok(())
+    }
+
+    #[test]
+    fn test_part2() -> Result<()> {
+        assert_eq!(part2(&as_input(INPUT)?), 400);
+        Ok(())
+    }
+
+    #[test]
+    fn test_frame() -> Result<()> {
+        let patterns = as_input(INPUT)?;
+        let frame = patterns[0].frame("Pattern 1");
+        assert_eq!(frame.plain().lines().nth(1), Some("#.##..##."));
+
+        // The mirror is between columns 4 and 5, and without the smudge between rows 3 and 4.
+        let color = |x, y| frame.cells[(x, y)].color;
+        assert_eq!(color(4, 0), Some(Color::Cyan));
+        assert_eq!(color(5, 6), Some(Color::Cyan));
+        assert_eq!(color(0, 2), Some(Color::Yellow));
+        assert_eq!(color(8, 3), Some(Color::Yellow));
+        assert_eq!(color(0, 0), Some(Color::Dim));
+        Ok(())
+    }
+}
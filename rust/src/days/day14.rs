@@ -0,0 +1,162 @@
+//! Day 14: Parabolic Reflector Dish
+
+use anyhow::{bail, Result};
+
+use crate::cycle;
+use crate::geometry::Dir;
+use crate::grid::Grid;
+use crate::Solution;
+
+pub type Input = Platform;
+
+/// The platform of round rocks (`O`), which roll when it's tilted, and cube rocks (`#`), which
+/// don't.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Platform {
+    pub grid: Grid<char>,
+}
+
+impl Platform {
+    /// Rolls every round rock towards `dir` until it hits the edge or another rock.
+    pub fn tilt(&mut self, dir: Dir) {
+        let (width, height) = (self.grid.width(), self.grid.height());
+        let (lines, len) = match dir {
+            Dir::Up | Dir::Down => (width, height),
+            Dir::Left | Dir::Right => (height, width),
+        };
+        // The `i`th position of a line, counting from the edge the rocks roll towards.
+        let pos = |line: usize, i: usize| match dir {
+            Dir::Up => (line, i),
+            Dir::Down => (line, height - 1 - i),
+            Dir::Left => (i, line),
+            Dir::Right => (width - 1 - i, line),
+        };
+
+        for line in 0..lines {
+            let mut free = 0;
+            for i in 0..len {
+                let (x, y) = pos(line, i);
+                match self.grid[(x, y)] {
+                    '#' => free = i + 1,
+                    'O' => {
+                        self.grid[(x, y)] = '.';
+                        self.grid[pos(line, free)] = 'O';
+                        free += 1;
+                    }
+                    _ => {}
+                }
+            }
+        }
+    }
+
+    /// Tilts north, west, south and east in turn.
+    pub fn spin_cycle(&mut self) {
+        for dir in [Dir::Up, Dir::Left, Dir::Down, Dir::Right] {
+            self.tilt(dir);
+        }
+    }
+
+    /// The load on the north support beams, where each round rock counts its distance from the
+    /// south edge.
+    pub fn load(&self) -> usize {
+        self.grid
+            .iter()
+            .filter(|(_, &c)| c == 'O')
+            .map(|((_, y), _)| self.grid.height() - y)
+            .sum()
+    }
+}
+
+pub fn part1(input: &Input) -> usize {
+    let mut platform = input.clone();
+    platform.tilt(Dir::Up);
+    platform.load()
+}
+
+pub fn part2(input: &Input) -> usize {
+    cycle::nth(input.clone(), 1_000_000_000, |platform| {
+        let mut platform = platform.clone();
+        platform.spin_cycle();
+        platform
+    })
+    .load()
+}
+
+pub struct Day14;
+
+impl Solution for Day14 {
+    const DAY: u8 = 14;
+    type Input = Input;
+    type Output1 = usize;
+    type Output2 = usize;
+
+    fn parse(input: &str) -> Result<Input> {
+        parse(input)
+    }
+
+    fn part1(input: &Input) -> usize {
+        part1(input)
+    }
+
+    fn part2(input: &Input) -> usize {
+        part2(input)
+    }
+}
+
+/// Parses the puzzle input.
+pub fn parse(input: &str) -> Result<Input> {
+    let grid = Grid::parse_with(input.trim_end(), |c| match c {
+        'O' | '#' | '.' => Ok(c),
+        _ => bail!("invalid tile, expected O, # or ."),
+    })?;
+    Ok(Platform { grid })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const INPUT: &str = include_str!("../../../input/examples/day14");
+
+    #[test]
+    fn test_part1() -> Result<()> {
+        assert_eq!(part1(&parse(INPUT)?), 136);
+        Ok(())
+    }
+
+    #[test]
+    fn test_part2() -> Result<()> {
+        assert_eq!(part2(&parse(INPUT)?), 64);
+        Ok(())
+    }
+
+    #[test]
+    fn test_spin_cycle() -> Result<()> {
+        let mut platform = parse(INPUT)?;
+        platform.spin_cycle();
+        assert_eq!(
+            platform.grid.to_string(),
+            "\
+.....#....
+....#...O#
+...OO##...
+.OO#......
+.....OOO#.
+.O#...O#.#
+....O#....
+......OOOO
+#...O###..
+#..OO#...."
+        );
+
+        let mut platform = parse("O.#\n.O.\n..O")?;
+        platform.tilt(Dir::Right);
+        assert_eq!(platform.grid.to_string(), ".O#\n..O\n..O");
+        platform.tilt(Dir::Down);
+        assert_eq!(platform.grid.to_string(), "..#\n..O\n.OO");
+        platform.tilt(Dir::Left);
+        assert_eq!(platform.grid.to_string(), "..#\nO..\nOO.");
+        assert!(parse("O.x").is_err());
+        Ok(())
+    }
+}
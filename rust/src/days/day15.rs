@@ -0,0 +1,202 @@
+//! Day 15: Lens Library
+
+use anyhow::Result;
+
+use crate::parse::{int, pair, parse_all, preceded, sep_by1, tag, take_while1, PResult, Parser};
+use crate::Solution;
+
+pub type Input = Vec<Step>;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Step {
+    /// The step as written in the input, which is what gets hashed in part 1.
+    pub text: String,
+    pub label: String,
+    pub op: Op,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Op {
+    /// `-`, takes the lens with the label out of its box.
+    Remove,
+    /// `=` and a focal length, puts a lens in its box or replaces the one with the same label.
+    Insert(u8),
+}
+
+/// The Holiday ASCII String Helper algorithm: for each byte, add it, multiply by 17 and keep
+/// the remainder of dividing by 256.
+pub fn holiday_hash(s: &str) -> u8 {
+    s.bytes()
+        .fold(0, |hash: u8, b| hash.wrapping_add(b).wrapping_mul(17))
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Lens {
+    pub label: String,
+    pub focal_length: u8,
+}
+
+/// The 256 boxes, each holding its lenses in the order they were put in.
+#[derive(Debug, Clone)]
+pub struct LensBoxes {
+    pub boxes: [Vec<Lens>; 256],
+}
+
+impl Default for LensBoxes {
+    fn default() -> Self {
+        Self {
+            boxes: std::array::from_fn(|_| vec![]),
+        }
+    }
+}
+
+impl LensBoxes {
+    /// Carries out `step` on the box its label hashes to. A replaced lens keeps its place.
+    pub fn apply(&mut self, step: &Step) {
+        let lenses = &mut self.boxes[holiday_hash(&step.label) as usize];
+        let pos = lenses.iter().position(|lens| lens.label == step.label);
+
+        match (step.op, pos) {
+            (Op::Remove, Some(i)) => {
+                lenses.remove(i);
+            }
+            (Op::Remove, None) => {}
+            (Op::Insert(focal_length), Some(i)) => lenses[i].focal_length = focal_length,
+            (Op::Insert(focal_length), None) => lenses.push(Lens {
+                label: step.label.clone(),
+                focal_length,
+            }),
+        }
+    }
+
+    /// The sum over all lenses of their box number, slot number and focal length multiplied,
+    /// where boxes count from 1 and slots from 1 within their box.
+    pub fn focusing_power(&self) -> usize {
+        self.boxes
+            .iter()
+            .enumerate()
+            .flat_map(|(b, lenses)| {
+                lenses
+                    .iter()
+                    .enumerate()
+                    .map(move |(slot, lens)| (b + 1) * (slot + 1) * lens.focal_length as usize)
+            })
+            .sum()
+    }
+}
+
+pub fn part1(input: &Input) -> usize {
+    input
+        .iter()
+        .map(|step| holiday_hash(&step.text) as usize)
+        .sum()
+}
+
+pub fn part2(input: &Input) -> usize {
+    let mut boxes = LensBoxes::default();
+    for step in input {
+        boxes.apply(step);
+    }
+    boxes.focusing_power()
+}
+
+pub struct Day15;
+
+impl Solution for Day15 {
+    const DAY: u8 = 15;
+    type Input = Input;
+    type Output1 = usize;
+    type Output2 = usize;
+
+    fn parse(input: &str) -> Result<Input> {
+        parse(input)
+    }
+
+    fn part1(input: &Input) -> usize {
+        part1(input)
+    }
+
+    fn part2(input: &Input) -> usize {
+        part2(input)
+    }
+}
+
+fn op(input: &str) -> PResult<'_, Op> {
+    match input.strip_prefix('-') {
+        Some(rest) => Ok((Op::Remove, rest)),
+        None => preceded(tag("="), int())
+            .map(Op::Insert)
+            .named("`=` or `-`")
+            .parse(input),
+    }
+}
+
+fn step(input: &str) -> PResult<'_, Step> {
+    let label = take_while1(|c| c.is_ascii_lowercase(), "label");
+    let ((label, op), rest) = pair(label, op).parse(input)?;
+    let text = &input[..input.len() - rest.len()];
+    let step = Step {
+        text: text.to_string(),
+        label: label.to_string(),
+        op,
+    };
+    Ok((step, rest))
+}
+
+/// Parses the puzzle input, a single line of steps separated by bare commas, as part 1 hashes
+/// the steps exactly as written.
+pub fn parse(input: &str) -> Result<Input> {
+    Ok(parse_all(input, sep_by1(step, tag(",")))?)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::error::ParseError;
+
+    const INPUT: &str = include_str!("../../../input/examples/day15");
+
+    #[test]
+    fn test_part1() -> Result<()> {
+        assert_eq!(holiday_hash("HASH"), 52);
+        assert_eq!(part1(&parse(INPUT)?), 1320);
+
+        // Steps are hashed as written, not as parsed.
+        let steps = parse("rn=01,cm=+1")?;
+        assert_eq!(steps[0].op, Op::Insert(1));
+        assert_eq!(steps[1].text, "cm=+1");
+        assert_eq!(
+            part1(&steps),
+            holiday_hash("rn=01") as usize + holiday_hash("cm=+1") as usize
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_part2() -> Result<()> {
+        let steps = parse(INPUT)?;
+        let mut boxes = LensBoxes::default();
+        for step in &steps[..6] {
+            boxes.apply(step);
+        }
+        let labels = |b: usize| boxes.boxes[b].iter().map(|l| &l.label).collect::<Vec<_>>();
+        assert_eq!(labels(0), ["rn", "cm"]);
+        assert_eq!(labels(3), ["pc"]);
+        assert_eq!(boxes.boxes[0][1].focal_length, 2);
+
+        assert_eq!(part2(&steps), 145);
+        assert!(parse("rn=1,cm*").is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_parse_error() {
+        let err = parse("rn=1, cm-").unwrap_err();
+        let err = err.downcast_ref::<ParseError>().unwrap();
+        assert_eq!((err.line, err.column), (1, 6));
+
+        let err = parse("rn=1,cm-,\nqp=3\n").unwrap_err();
+        let err = err.downcast_ref::<ParseError>().unwrap();
+        assert_eq!((err.line, err.column), (1, 10));
+    }
+}
@@ -0,0 +1,201 @@
+//! Day 16: The Floor Will Be Lava
+
+use anyhow::{bail, Result};
+use rayon::prelude::*;
+
+use crate::geometry::{self, Dir};
+use crate::grid::Grid;
+use crate::viz::{Color, Frame, Visualizer};
+use crate::Solution;
+
+pub type Input = Contraption;
+
+type Pos = geometry::Pos<i32>;
+
+/// The mirrors (`/`, `\`) and splitters (`|`, `-`) on empty space (`.`) that beams pass through.
+#[derive(Debug)]
+pub struct Contraption {
+    pub grid: Grid<char>,
+}
+
+impl Contraption {
+    /// For each tile, the directions beams passed through it in as a bitmask of `1 << dir`, for
+    /// a beam entering at `start` going `dir`. A beam stops where another one already went the
+    /// same way, so beams that loop end.
+    pub fn trace(&self, start: Pos, dir: Dir) -> Grid<u8> {
+        let mut seen = self.grid.map(|_| 0u8);
+        let mut beams = vec![(start, dir)];
+
+        while let Some((pos, dir)) = beams.pop() {
+            let (Some(&tile), Some(passed)) = (self.grid.get_pos(pos), seen.get_pos_mut(pos))
+            else {
+                continue;
+            };
+            if *passed & (1 << dir as u8) != 0 {
+                continue;
+            }
+            *passed |= 1 << dir as u8;
+
+            let (dir, split) = deflect(tile, dir);
+            beams.push((pos.step(dir), dir));
+            if let Some(split) = split {
+                beams.push((pos.step(split), split));
+            }
+        }
+        seen
+    }
+
+    /// The number of tiles at least one beam passes through.
+    pub fn energized(&self, start: Pos, dir: Dir) -> usize {
+        self.trace(start, dir)
+            .iter()
+            .filter(|(_, &d)| d != 0)
+            .count()
+    }
+
+    /// Every tile on the edge with the direction into the grid, twice for the corners.
+    pub fn edge_entries(&self) -> Vec<(Pos, Dir)> {
+        let (width, height) = (self.grid.width() as i32, self.grid.height() as i32);
+        let rows = (0..height).flat_map(|y| {
+            [
+                (Pos::new(0, y), Dir::Right),
+                (Pos::new(width - 1, y), Dir::Left),
+            ]
+        });
+        let columns = (0..width).flat_map(|x| {
+            [
+                (Pos::new(x, 0), Dir::Down),
+                (Pos::new(x, height - 1), Dir::Up),
+            ]
+        });
+        rows.chain(columns).collect()
+    }
+
+    /// The entry that energizes the most tiles, trying them all in parallel.
+    pub fn best_entry(&self) -> Option<(Pos, Dir, usize)> {
+        self.edge_entries()
+            .into_par_iter()
+            .map(|(pos, dir)| (pos, dir, self.energized(pos, dir)))
+            .max_by_key(|&(_, _, energized)| energized)
+    }
+
+    /// The energized tiles in yellow for a beam entering at `start` going `dir`.
+    pub fn frame(&self, title: &str, start: Pos, dir: Dir) -> Frame {
+        let mut frame = Frame::new(title, &self.grid);
+        for (pos, &passed) in self.trace(start, dir).iter() {
+            if passed != 0 {
+                frame.paint(pos, Color::Yellow);
+            }
+        }
+        frame.paint_rest(Color::Dim);
+        frame
+    }
+}
+
+/// The direction a beam going `dir` leaves `tile` in, and the second one if it's split.
+fn deflect(tile: char, dir: Dir) -> (Dir, Option<Dir>) {
+    match (tile, dir) {
+        ('/', Dir::Right | Dir::Left) => (dir.turn_left(), None),
+        ('/', Dir::Up | Dir::Down) => (dir.turn_right(), None),
+        ('\\', Dir::Right | Dir::Left) => (dir.turn_right(), None),
+        ('\\', Dir::Up | Dir::Down) => (dir.turn_left(), None),
+        ('|', Dir::Right | Dir::Left) => (Dir::Up, Some(Dir::Down)),
+        ('-', Dir::Up | Dir::Down) => (Dir::Left, Some(Dir::Right)),
+        _ => (dir, None),
+    }
+}
+
+pub fn part1(input: &Input) -> usize {
+    input.energized(Pos::new(0, 0), Dir::Right)
+}
+
+pub fn part2(input: &Input) -> usize {
+    input.best_entry().map_or(0, |(_, _, energized)| energized)
+}
+
+pub struct Day16;
+
+impl Solution for Day16 {
+    const DAY: u8 = 16;
+    type Input = Input;
+    type Output1 = usize;
+    type Output2 = usize;
+
+    fn parse(input: &str) -> Result<Input> {
+        parse(input)
+    }
+
+    fn part1(input: &Input) -> usize {
+        part1(input)
+    }
+
+    fn part2(input: &Input) -> usize {
+        part2(input)
+    }
+
+    fn visualize(input: &Input, viz: &mut Visualizer) -> Result<()> {
+        let start = Pos::new(0, 0);
+        viz.show(&input.frame("Energized from the top left", start, Dir::Right))?;
+        if let Some((pos, dir, _)) = input.best_entry() {
+            let title = format!(
+                "Energized from the best entry, ({}, {}) going {dir:?}",
+                pos.x, pos.y
+            );
+            viz.show(&input.frame(&title, pos, dir))?;
+        }
+        Ok(())
+    }
+}
+
+/// Parses the puzzle input.
+pub fn parse(input: &str) -> Result<Input> {
+    let grid = Grid::parse_with(input.trim_end(), |c| match c {
+        '.' | '/' | '\\' | '|' | '-' => Ok(c),
+        _ => bail!("invalid tile, expected ., /, \\, | or -"),
+    })?;
+    Ok(Contraption { grid })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const INPUT: &str = include_str!("../../../input/examples/day16");
+
+    #[test]
+    fn test_part1() -> Result<()> {
+        let contraption = parse(INPUT)?;
+        assert_eq!(part1(&contraption), 46);
+
+        // Without the loop protection, the beam going down from the splitter would go round
+        // and back to it forever.
+        let looping = parse(".|..\\\n.....\n.\\../")?;
+        assert_eq!(looping.energized(Pos::new(0, 0), Dir::Right), 11);
+        Ok(())
+    }
+
+    #[test]
+    fn test_frame() -> Result<()> {
+        let contraption = parse(".|.\n...\n.-.")?;
+        let frame = contraption.frame("Test", Pos::new(0, 0), Dir::Right);
+        assert_eq!(frame.plain(), "Test\n.|.\n...\n.-.\n");
+
+        let color = |x, y| frame.cells[(x, y)].color;
+        assert_eq!(color(1, 1), Some(Color::Yellow));
+        assert_eq!(color(2, 2), Some(Color::Yellow));
+        assert_eq!(color(2, 0), Some(Color::Dim));
+        Ok(())
+    }
+
+    #[test]
+    fn test_part2() -> Result<()> {
+        let contraption = parse(INPUT)?;
+        assert_eq!(contraption.edge_entries().len(), 40);
+        assert_eq!(part2(&contraption), 51);
+        assert_eq!(
+            contraption.best_entry().map(|(pos, dir, _)| (pos, dir)),
+            Some((Pos::new(3, 0), Dir::Down))
+        );
+        Ok(())
+    }
+}
@@ -0,0 +1,244 @@
+//! Day 17: Clumsy Crucible
+
+use anyhow::{bail, Context, Result};
+
+use crate::geometry::{self, Dir};
+use crate::graph;
+use crate::grid::Grid;
+use crate::viz::{Color, Frame, Visualizer};
+use crate::Solution;
+
+pub type Input = City;
+
+type Pos = geometry::Pos<i32>;
+
+#[derive(Debug)]
+pub struct City {
+    /// The heat lost when a crucible enters each block.
+    pub heat_loss: Grid<u8>,
+}
+
+/// How many blocks a crucible has to and may go in one direction before turning.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Crucible {
+    pub min_run: u8,
+    pub max_run: u8,
+}
+
+impl Crucible {
+    pub const NORMAL: Self = Self {
+        min_run: 1,
+        max_run: 3,
+    };
+    pub const ULTRA: Self = Self {
+        min_run: 4,
+        max_run: 10,
+    };
+}
+
+/// Where a crucible is, the direction it moved in and how many blocks it moved in it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct State {
+    pub pos: Pos,
+    pub dir: Dir,
+    pub run: u8,
+}
+
+#[derive(Debug, Clone)]
+pub struct Route {
+    pub heat_loss: u32,
+    /// From the top left block to the bottom right one.
+    pub path: Vec<State>,
+}
+
+impl City {
+    /// The route from the top left to the bottom right block losing the least heat. In a city
+    /// of one block the crucible is already there, without having to move at all.
+    pub fn best_route(&self, crucible: Crucible) -> Option<Route> {
+        let end = Pos::from_grid((self.heat_loss.width() - 1, self.heat_loss.height() - 1));
+        let starts = [Dir::Right, Dir::Down].map(|dir| State {
+            pos: Pos::new(0, 0),
+            dir,
+            run: 0,
+        });
+        if end == Pos::new(0, 0) {
+            return Some(Route {
+                heat_loss: 0,
+                path: vec![starts[0]],
+            });
+        }
+
+        let (goal, paths) = graph::dijkstra_to(
+            starts,
+            |state| self.moves(state, crucible),
+            |state| state.pos == end && state.run >= crucible.min_run,
+        );
+        let goal = goal?;
+        Some(Route {
+            heat_loss: paths.distance(goal)?,
+            path: paths.path_to(goal)?,
+        })
+    }
+
+    /// The states one block on from `state`, with the heat lost entering the block.
+    fn moves(&self, state: State, crucible: Crucible) -> impl Iterator<Item = (State, u32)> + '_ {
+        [state.dir, state.dir.turn_left(), state.dir.turn_right()]
+            .into_iter()
+            .filter_map(move |dir| {
+                let run = if dir == state.dir {
+                    (state.run < crucible.max_run).then_some(state.run + 1)?
+                } else {
+                    (state.run >= crucible.min_run).then_some(1)?
+                };
+                let pos = state.pos.step(dir);
+                let heat_loss = *self.heat_loss.get_pos(pos)?;
+                Some((State { pos, dir, run }, heat_loss as u32))
+            })
+    }
+
+    /// The city with arrows showing the direction `route` entered each block on it in.
+    pub fn draw(&self, route: &Route) -> Grid<char> {
+        let mut grid = self
+            .heat_loss
+            .map(|&h| char::from_digit(h as u32, 10).unwrap_or('?'));
+        for state in &route.path[1..] {
+            grid[state.pos] = match state.dir {
+                Dir::Right => '>',
+                Dir::Down => 'v',
+                Dir::Left => '<',
+                Dir::Up => '^',
+            };
+        }
+        grid
+    }
+
+    /// The drawn route in yellow on the dimmed city.
+    pub fn frame(&self, title: &str, route: &Route) -> Frame {
+        let mut frame = Frame::new(title, &self.draw(route));
+        for state in &route.path {
+            if let Some(pos) = state.pos.to_grid() {
+                frame.paint(pos, Color::Yellow);
+            }
+        }
+        frame.paint_rest(Color::Dim);
+        frame
+    }
+}
+
+fn min_heat_loss(city: &City, crucible: Crucible) -> u32 {
+    city.best_route(crucible)
+        .expect("No route to the bottom right")
+        .heat_loss
+}
+
+pub fn part1(input: &Input) -> u32 {
+    min_heat_loss(input, Crucible::NORMAL)
+}
+
+pub fn part2(input: &Input) -> u32 {
+    min_heat_loss(input, Crucible::ULTRA)
+}
+
+pub struct Day17;
+
+impl Solution for Day17 {
+    const DAY: u8 = 17;
+    type Input = Input;
+    type Output1 = u32;
+    type Output2 = u32;
+
+    fn parse(input: &str) -> Result<Input> {
+        parse(input)
+    }
+
+    fn part1(input: &Input) -> u32 {
+        part1(input)
+    }
+
+    fn part2(input: &Input) -> u32 {
+        part2(input)
+    }
+
+    fn visualize(input: &Input, viz: &mut Visualizer) -> Result<()> {
+        for (name, crucible) in [
+            ("crucible", Crucible::NORMAL),
+            ("ultra crucible", Crucible::ULTRA),
+        ] {
+            let route = input.best_route(crucible).context("No route")?;
+            let title = format!("Best {name} route, losing {}", route.heat_loss);
+            viz.show(&input.frame(&title, &route))?;
+        }
+        Ok(())
+    }
+}
+
+/// Parses the puzzle input.
+pub fn parse(input: &str) -> Result<Input> {
+    let heat_loss = Grid::parse_with(input.trim_end(), |c| {
+        c.to_digit(10).map(|d| d as u8).context("not a digit")
+    })?;
+    if heat_loss.width() == 0 {
+        bail!("The city has no blocks");
+    }
+    Ok(City { heat_loss })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const INPUT: &str = include_str!("../../../input/examples/day17");
+
+    /// Checks the path follows the crucible's rules and loses the heat it claims to.
+    fn check_route(city: &City, crucible: Crucible) -> u32 {
+        let route = city.best_route(crucible).unwrap();
+        let mut heat_loss = 0;
+        for pair in route.path.windows(2) {
+            let (from, to) = (pair[0], pair[1]);
+            assert_eq!(from.pos.step(to.dir), to.pos);
+            assert!(to.run <= crucible.max_run);
+            if to.dir != from.dir {
+                assert!(from.run >= crucible.min_run && to.run == 1);
+            }
+            heat_loss += city.heat_loss[to.pos] as u32;
+        }
+        assert_eq!(heat_loss, route.heat_loss);
+        route.heat_loss
+    }
+
+    #[test]
+    fn test_part1() -> Result<()> {
+        let city = parse(INPUT)?;
+        assert_eq!(part1(&city), 102);
+        assert_eq!(check_route(&city, Crucible::NORMAL), 102);
+        Ok(())
+    }
+
+    #[test]
+    fn test_part2() -> Result<()> {
+        let city = parse(INPUT)?;
+        assert_eq!(part2(&city), 94);
+        assert_eq!(check_route(&city, Crucible::ULTRA), 94);
+        assert_eq!(part2(&parse("7")?), 0);
+
+        let city = parse("111111111111\n999999999991\n999999999991\n999999999991\n999999999991")?;
+        let route = city.best_route(Crucible::ULTRA).unwrap();
+        assert_eq!(route.heat_loss, 71);
+        assert_eq!(
+            city.draw(&route).to_string(),
+            "\
+1>>>>>>>1111
+9999999v9991
+9999999v9991
+9999999v9991
+9999999v>>>>"
+        );
+
+        let frame = city.frame("Test", &route);
+        assert!(frame.plain().starts_with("Test\n1>>>>>>>1111\n"));
+        assert_eq!(frame.cells[(0, 0)].color, Some(Color::Yellow));
+        assert_eq!(frame.cells[(7, 3)].color, Some(Color::Yellow));
+        assert_eq!(frame.cells[(8, 0)].color, Some(Color::Dim));
+        Ok(())
+    }
+}
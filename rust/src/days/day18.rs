@@ -0,0 +1,144 @@
+//! Day 18: Lavaduct Lagoon
+
+use anyhow::Result;
+
+use crate::geometry::{self, Dir, Pos};
+use crate::parse::{
+    delimited, int, lines, pair, parse_all, preceded, tag, take_while1, terminated, Failure,
+    PResult, Parser,
+};
+use crate::Solution;
+
+pub type Input = Vec<Instruction>;
+
+/// One line of the dig plan: dig `meters` towards `dir`, and the edge's color.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Instruction {
+    pub dir: Dir,
+    pub meters: i64,
+    pub color: u32,
+}
+
+impl Instruction {
+    /// The instruction hidden in the color: the first five hex digits are the meters, the last
+    /// one the direction, 0 to 3 for right, down, left and up.
+    pub fn decode(&self) -> (Dir, i64) {
+        (
+            Dir::ALL[(self.color & 0xf) as usize],
+            (self.color >> 4) as i64,
+        )
+    }
+}
+
+/// The cubic meters of lava the lagoon dug by `steps` holds: the trench, one meter wide
+/// around the polygon through the centres of its corner cubes, and everything inside it.
+///
+/// The trench is counted from the polygon's edges and the inside from its area with Pick's
+/// theorem, so huge plans don't need a grid to flood fill.
+pub fn lagoon_volume(steps: impl IntoIterator<Item = (Dir, i64)>) -> i64 {
+    let mut pos = Pos::default();
+    let mut trench = 0;
+    let mut vertices = vec![];
+
+    for (dir, meters) in steps {
+        pos += dir.delta() * meters;
+        trench += meters;
+        vertices.push(pos);
+    }
+
+    let double_area = geometry::double_area(&vertices);
+    geometry::interior_points(double_area, trench) + trench
+}
+
+pub fn part1(input: &Input) -> i64 {
+    lagoon_volume(input.iter().map(|i| (i.dir, i.meters)))
+}
+
+pub fn part2(input: &Input) -> i64 {
+    lagoon_volume(input.iter().map(Instruction::decode))
+}
+
+pub struct Day18;
+
+impl Solution for Day18 {
+    const DAY: u8 = 18;
+    type Input = Input;
+    type Output1 = i64;
+    type Output2 = i64;
+
+    fn parse(input: &str) -> Result<Input> {
+        parse(input)
+    }
+
+    fn part1(input: &Input) -> i64 {
+        part1(input)
+    }
+
+    fn part2(input: &Input) -> i64 {
+        part2(input)
+    }
+}
+
+fn dir(input: &str) -> PResult<'_, Dir> {
+    let dir = match input.chars().next() {
+        Some('R') => Dir::Right,
+        Some('D') => Dir::Down,
+        Some('L') => Dir::Left,
+        Some('U') => Dir::Up,
+        _ => return Err(Failure::expected(input, "direction")),
+    };
+    Ok((dir, &input[1..]))
+}
+
+/// Six hex digits after a `#`, where the last one has to be a valid direction.
+fn color(input: &str) -> PResult<'_, u32> {
+    let hex_digits = take_while1(|c| c.is_ascii_hexdigit(), "color");
+    let (hex, rest) = preceded(tag("#"), hex_digits).parse(input)?;
+
+    match u32::from_str_radix(hex, 16) {
+        Ok(color) if hex.len() == 6 && color & 0xf < 4 => Ok((color, rest)),
+        _ => Err(Failure::invalid(&input[1..], hex.len(), "color")),
+    }
+}
+
+/// Parses the puzzle input.
+pub fn parse(input: &str) -> Result<Input> {
+    let instruction = pair(
+        terminated(dir, tag(" ")),
+        pair(
+            terminated(int(), tag(" ")),
+            delimited(tag("("), color, tag(")")),
+        ),
+    )
+    .map(|(dir, (meters, color))| Instruction { dir, meters, color });
+
+    Ok(parse_all(input, lines(instruction))?)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const INPUT: &str = include_str!("../../../input/examples/day18");
+
+    #[test]
+    fn test_part1() -> Result<()> {
+        assert_eq!(part1(&parse(INPUT)?), 62);
+
+        // A single row of cubes has nothing inside the trench.
+        assert_eq!(lagoon_volume([(Dir::Right, 4), (Dir::Left, 4)]), 5);
+        Ok(())
+    }
+
+    #[test]
+    fn test_part2() -> Result<()> {
+        let plan = parse(INPUT)?;
+        assert_eq!(plan[0].decode(), (Dir::Right, 461937));
+        assert_eq!(part2(&plan), 952408144115);
+
+        let err = parse("R 6 (#70c714)").unwrap_err();
+        assert!(err.to_string().contains("invalid color `70c714`"), "{err}");
+        assert!(parse("X 6 (#70c710)").is_err());
+        Ok(())
+    }
+}
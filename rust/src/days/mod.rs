@@ -0,0 +1,115 @@
+//! The solutions, one module per day. Each exposes `parse`, `part1` and `part2` over its own
+//! `Input` type, plus a `DayNN` implementing `Solution` for the runner.
+
+use anyhow::{bail, Context, Result};
+
+use crate::input::Source;
+use crate::solution::{Day, Parts};
+
+pub mod day01;
+pub mod day02;
+pub mod day03;
+pub mod day04;
+pub mod day05;
+pub mod day06;
+pub mod day07;
+pub mod day08;
+pub mod day09;
+pub mod day10;
+pub mod day11;
+pub mod day12;
+pub mod day13;
+pub mod day14;
+pub mod day15;
+pub mod day16;
+pub mod day17;
+pub mod day18;
+
+pub const DAYS: &[Day] = &[
+    Day::new::<day01::Day01>(),
+    Day::new::<day02::Day02>(),
+    Day::new::<day03::Day03>(),
+    Day::new::<day04::Day04>(),
+    Day::new::<day05::Day05>(),
+    Day::new::<day06::Day06>(),
+    Day::new::<day07::Day07>(),
+    Day::new::<day08::Day08>(),
+    Day::new::<day09::Day09>(),
+    Day::new::<day10::Day10>(),
+    Day::new::<day11::Day11>(),
+    Day::new::<day12::Day12>(),
+    Day::new::<day13::Day13>(),
+    Day::new::<day14::Day14>(),
+    Day::new::<day15::Day15>(),
+    Day::new::<day16::Day16>(),
+    Day::new::<day17::Day17>(),
+    Day::new::<day18::Day18>(),
+];
+
+pub fn get(day: u8) -> Option<&'static Day> {
+    DAYS.iter().find(|d| d.day == day)
+}
+
+/// The whole of a `dayNN` binary: runs the day on the inputs given as arguments, or on its
+/// default input, printing the answers and timing like `aoc NN` does.
+pub fn run_bin(day: u8) -> Result<()> {
+    let day = get(day).with_context(|| format!("Day {day} is not implemented"))?;
+    let mut sources = std::env::args()
+        .skip(1)
+        .map(|arg| Source::from(arg.as_str()))
+        .collect::<Vec<_>>();
+    if sources.is_empty() {
+        sources.push(day.default_input());
+    }
+
+    for source in &sources {
+        let run = day.run_timed(source, Parts::Both)?;
+        for (part, answer) in run.answers.parts() {
+            println!("Part{part}: {answer}");
+        }
+        println!("{}", run.timing());
+    }
+    Ok(())
+}
+
+/// Selects days from `all`, a single day (`5`) or an inclusive range (`3-7`).
+pub fn select(spec: &str) -> Result<Vec<&'static Day>> {
+    if spec == "all" {
+        return Ok(DAYS.iter().collect());
+    }
+
+    let (from, to) = match spec.split_once('-') {
+        Some((from, to)) => (from, to),
+        None => (spec, spec),
+    };
+    let from = from.parse::<u8>().context("Invalid day")?;
+    let to = to.parse::<u8>().context("Invalid day")?;
+
+    let days = (from..=to)
+        .map(|day| get(day).with_context(|| format!("Day {day} is not implemented")))
+        .collect::<Result<Vec<_>>>()?;
+
+    if days.is_empty() {
+        bail!("No days in {spec}");
+    }
+    Ok(days)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn day_nrs(spec: &str) -> Result<Vec<u8>> {
+        Ok(select(spec)?.into_iter().map(|d| d.day).collect())
+    }
+
+    #[test]
+    fn test_select() -> Result<()> {
+        assert_eq!(day_nrs("5")?, vec![5]);
+        assert_eq!(day_nrs("03-05")?, vec![3, 4, 5]);
+        assert_eq!(day_nrs("all")?.len(), DAYS.len());
+        assert!(day_nrs("7-3").is_err());
+        assert!(day_nrs("24").is_err());
+        Ok(())
+    }
+}
@@ -0,0 +1,168 @@
+//! Parse errors that point at the offending token of the input, for quickly finding what's wrong
+//! in a malformed puzzle input.
+
+use std::error::Error;
+use std::fmt;
+use std::str::FromStr;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    pub message: String,
+    pub file: Option<String>,
+    /// 1-based line number within the input.
+    pub line: usize,
+    /// 1-based column, in characters, where the offending text starts.
+    pub column: usize,
+    /// Length of the offending text in characters, at least 1.
+    pub len: usize,
+    /// The whole line the error is on.
+    pub text: String,
+}
+
+impl ParseError {
+    /// An error at `span`, which should be a slice of `line` as produced by `split`, `trim` and
+    /// friends. If it isn't, the error points at the start of the line.
+    pub fn new(line: &str, span: &str, message: impl Into<String>) -> Self {
+        let offset = (span.as_ptr() as usize)
+            .checked_sub(line.as_ptr() as usize)
+            .filter(|&o| o + span.len() <= line.len())
+            .unwrap_or(0);
+
+        Self {
+            message: message.into(),
+            file: None,
+            line: 1,
+            column: line[..offset].chars().count() + 1,
+            len: span.chars().count().max(1),
+            text: line.to_string(),
+        }
+    }
+
+    /// An error for something expected but missing, pointing just past the end of `line`.
+    pub fn missing(line: &str, what: &str) -> Self {
+        Self::new(line, &line[line.len()..], format!("missing {what}"))
+    }
+
+    pub fn on_line(mut self, line: usize) -> Self {
+        self.line = line;
+        self
+    }
+
+    pub fn in_file(mut self, file: impl Into<String>) -> Self {
+        self.file = Some(file.into());
+        self
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let gutter = " ".repeat(self.line.to_string().len());
+        let file = self.file.as_deref().unwrap_or("<input>");
+
+        writeln!(f, "{}", self.message)?;
+        writeln!(f, "{gutter}--> {file}:{}:{}", self.line, self.column)?;
+        writeln!(f, "{gutter} |")?;
+        writeln!(f, "{} | {}", self.line, self.text)?;
+        write!(
+            f,
+            "{gutter} | {}{}",
+            " ".repeat(self.column - 1),
+            "^".repeat(self.len)
+        )
+    }
+}
+
+impl Error for ParseError {}
+
+/// Parses `token`, a slice of `line`, reporting a failure at the token.
+pub fn parse_token<T: FromStr>(line: &str, token: &str, what: &str) -> Result<T, ParseError> {
+    token
+        .parse()
+        .map_err(|_| ParseError::new(line, token, format!("invalid {what} `{token}`")))
+}
+
+/// Parses each line of `input`, numbering errors by the line they're on.
+pub fn parse_lines<T>(input: &str) -> Result<Vec<T>, ParseError>
+where
+    T: FromStr<Err = ParseError>,
+{
+    input
+        .lines()
+        .enumerate()
+        .map(|(i, line)| line.parse().map_err(|e: ParseError| e.on_line(i + 1)))
+        .collect()
+}
+
+/// Turns the `None` of an exhausted `split` into an error pointing at the end of the line.
+pub trait Required<'a> {
+    fn required(self, line: &str, what: &str) -> Result<&'a str, ParseError>;
+
+    fn parse_required<T: FromStr>(self, line: &str, what: &str) -> Result<T, ParseError>
+    where
+        Self: Sized,
+    {
+        parse_token(line, self.required(line, what)?, what)
+    }
+}
+
+impl<'a> Required<'a> for Option<&'a str> {
+    fn required(self, line: &str, what: &str) -> Result<&'a str, ParseError> {
+        self.ok_or_else(|| ParseError::missing(line, what))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_display() {
+        let line = "Game 12: 3 blue, x4 red";
+        let token = line.split(' ').nth(4).unwrap();
+        let err = parse_token::<i32>(line, token, "count")
+            .unwrap_err()
+            .on_line(12)
+            .in_file("../input/day02");
+
+        assert_eq!((err.column, err.len), (18, 2));
+        assert_eq!(
+            err.to_string(),
+            "invalid count `x4`
+  --> ../input/day02:12:18
+   |
+12 | Game 12: 3 blue, x4 red
+   |                  ^^"
+        );
+    }
+
+    #[test]
+    fn test_missing() {
+        let line = "Card 1";
+        let err = line
+            .split(':')
+            .nth(1)
+            .required(line, "numbers")
+            .unwrap_err();
+        assert_eq!(err.message, "missing numbers");
+        assert_eq!((err.column, err.len), (7, 1));
+
+        let err = ParseError::new(line, "elsewhere", "not in line");
+        assert_eq!(err.column, 1);
+    }
+
+    #[test]
+    fn test_parse_lines() {
+        let err = parse_lines::<Num>("1\n2\nthree").unwrap_err();
+        assert_eq!((err.line, err.column, err.len), (3, 1, 5));
+    }
+
+    #[derive(Debug)]
+    struct Num;
+
+    impl FromStr for Num {
+        type Err = ParseError;
+        fn from_str(s: &str) -> Result<Self, Self::Err> {
+            parse_token::<i32>(s, s, "number").map(|_| Num)
+        }
+    }
+}
@@ -0,0 +1,319 @@
+//! Points, vectors and directions on the integer plane and in space.
+//!
+//! The y axis grows downwards, like rows of a puzzle input, so `Dir::Up` is a step towards
+//! `y - 1`.
+
+use std::fmt::Debug;
+use std::hash::Hash;
+use std::ops::{Add, AddAssign, Div, Mul, Neg, Sub, SubAssign};
+
+/// The signed integer types positions can be made of.
+pub trait Coord:
+    Copy
+    + Debug
+    + Default
+    + Eq
+    + Ord
+    + Hash
+    + Add<Output = Self>
+    + Sub<Output = Self>
+    + Mul<Output = Self>
+    + Div<Output = Self>
+    + Neg<Output = Self>
+{
+    const ZERO: Self;
+    const ONE: Self;
+
+    fn abs(self) -> Self;
+    fn to_usize(self) -> Option<usize>;
+    fn from_usize(v: usize) -> Self;
+}
+
+macro_rules! impl_coord {
+    ($($t:ty),*) => {
+        $(
+            impl Coord for $t {
+                const ZERO: Self = 0;
+                const ONE: Self = 1;
+
+                fn abs(self) -> Self {
+                    <$t>::abs(self)
+                }
+
+                fn to_usize(self) -> Option<usize> {
+                    usize::try_from(self).ok()
+                }
+
+                fn from_usize(v: usize) -> Self {
+                    v as $t
+                }
+            }
+        )*
+    };
+}
+
+impl_coord!(i32, i64, isize, i128);
+
+/// A point, or a vector between points, in two dimensions.
+#[derive(Debug, Default, Copy, Clone, Eq, PartialEq, Hash, PartialOrd, Ord)]
+pub struct Pos<T = i64> {
+    pub x: T,
+    pub y: T,
+}
+
+impl<T: Coord> Pos<T> {
+    pub const fn new(x: T, y: T) -> Self {
+        Self { x, y }
+    }
+
+    pub fn manhattan(&self, other: &Self) -> T {
+        (self.x - other.x).abs() + (self.y - other.y).abs()
+    }
+
+    pub fn chebyshev(&self, other: &Self) -> T {
+        (self.x - other.x).abs().max((self.y - other.y).abs())
+    }
+
+    pub fn step(self, dir: Dir) -> Self {
+        self + dir.delta()
+    }
+
+    /// The positions right, below, left and above, in the order of `Dir::ALL`.
+    pub fn neighbours4(self) -> impl Iterator<Item = Self> {
+        Dir::ALL.into_iter().map(move |dir| self.step(dir))
+    }
+
+    /// The positions around, diagonals included.
+    pub fn neighbours8(self) -> impl Iterator<Item = Self> {
+        Dir::ALL
+            .into_iter()
+            .flat_map(move |dir| [self.step(dir), self.step(dir).step(dir.turn_right())])
+    }
+
+    /// The `(x, y)` a `Grid` is indexed with, if neither coordinate is negative.
+    pub fn to_grid(self) -> Option<(usize, usize)> {
+        Some((self.x.to_usize()?, self.y.to_usize()?))
+    }
+
+    pub fn from_grid((x, y): (usize, usize)) -> Self {
+        Self::new(T::from_usize(x), T::from_usize(y))
+    }
+}
+
+impl<T: Coord> Add for Pos<T> {
+    type Output = Self;
+    fn add(self, rhs: Self) -> Self {
+        Self::new(self.x + rhs.x, self.y + rhs.y)
+    }
+}
+
+impl<T: Coord> Sub for Pos<T> {
+    type Output = Self;
+    fn sub(self, rhs: Self) -> Self {
+        Self::new(self.x - rhs.x, self.y - rhs.y)
+    }
+}
+
+impl<T: Coord> Mul<T> for Pos<T> {
+    type Output = Self;
+    fn mul(self, rhs: T) -> Self {
+        Self::new(self.x * rhs, self.y * rhs)
+    }
+}
+
+impl<T: Coord> Neg for Pos<T> {
+    type Output = Self;
+    fn neg(self) -> Self {
+        Self::new(-self.x, -self.y)
+    }
+}
+
+impl<T: Coord> AddAssign for Pos<T> {
+    fn add_assign(&mut self, rhs: Self) {
+        *self = *self + rhs;
+    }
+}
+
+impl<T: Coord> SubAssign for Pos<T> {
+    fn sub_assign(&mut self, rhs: Self) {
+        *self = *self - rhs;
+    }
+}
+
+/// A point, or a vector between points, in three dimensions.
+#[derive(Debug, Default, Copy, Clone, Eq, PartialEq, Hash, PartialOrd, Ord)]
+pub struct Pos3<T = i64> {
+    pub x: T,
+    pub y: T,
+    pub z: T,
+}
+
+impl<T: Coord> Pos3<T> {
+    pub const fn new(x: T, y: T, z: T) -> Self {
+        Self { x, y, z }
+    }
+
+    pub fn manhattan(&self, other: &Self) -> T {
+        (self.x - other.x).abs() + (self.y - other.y).abs() + (self.z - other.z).abs()
+    }
+
+    pub fn chebyshev(&self, other: &Self) -> T {
+        (self.x - other.x)
+            .abs()
+            .max((self.y - other.y).abs())
+            .max((self.z - other.z).abs())
+    }
+}
+
+impl<T: Coord> Add for Pos3<T> {
+    type Output = Self;
+    fn add(self, rhs: Self) -> Self {
+        Self::new(self.x + rhs.x, self.y + rhs.y, self.z + rhs.z)
+    }
+}
+
+impl<T: Coord> Sub for Pos3<T> {
+    type Output = Self;
+    fn sub(self, rhs: Self) -> Self {
+        Self::new(self.x - rhs.x, self.y - rhs.y, self.z - rhs.z)
+    }
+}
+
+impl<T: Coord> Mul<T> for Pos3<T> {
+    type Output = Self;
+    fn mul(self, rhs: T) -> Self {
+        Self::new(self.x * rhs, self.y * rhs, self.z * rhs)
+    }
+}
+
+impl<T: Coord> Neg for Pos3<T> {
+    type Output = Self;
+    fn neg(self) -> Self {
+        Self::new(-self.x, -self.y, -self.z)
+    }
+}
+
+impl<T: Coord> AddAssign for Pos3<T> {
+    fn add_assign(&mut self, rhs: Self) {
+        *self = *self + rhs;
+    }
+}
+
+impl<T: Coord> SubAssign for Pos3<T> {
+    fn sub_assign(&mut self, rhs: Self) {
+        *self = *self - rhs;
+    }
+}
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash, PartialOrd, Ord)]
+pub enum Dir {
+    Right,
+    Down,
+    Left,
+    Up,
+}
+
+impl Dir {
+    /// Clockwise, starting to the right.
+    pub const ALL: [Dir; 4] = [Dir::Right, Dir::Down, Dir::Left, Dir::Up];
+
+    pub fn turn_right(self) -> Self {
+        Self::ALL[(self as usize + 1) % 4]
+    }
+
+    pub fn turn_left(self) -> Self {
+        Self::ALL[(self as usize + 3) % 4]
+    }
+
+    pub fn reverse(self) -> Self {
+        Self::ALL[(self as usize + 2) % 4]
+    }
+
+    pub fn delta<T: Coord>(self) -> Pos<T> {
+        match self {
+            Dir::Right => Pos::new(T::ONE, T::ZERO),
+            Dir::Down => Pos::new(T::ZERO, T::ONE),
+            Dir::Left => Pos::new(-T::ONE, T::ZERO),
+            Dir::Up => Pos::new(T::ZERO, -T::ONE),
+        }
+    }
+
+    /// The direction of a unit step, if `delta` is one.
+    pub fn from_delta<T: Coord>(delta: Pos<T>) -> Option<Self> {
+        Self::ALL.into_iter().find(|dir| dir.delta() == delta)
+    }
+}
+
+/// Twice the area of the polygon through `vertices` in order, by the shoelace formula. Twice
+/// the area is always an integer, so it stays exact.
+pub fn double_area<T: Coord>(vertices: &[Pos<T>]) -> T {
+    let next = vertices.iter().cycle().skip(1);
+    vertices
+        .iter()
+        .zip(next)
+        .fold(T::ZERO, |acc, (a, b)| acc + a.x * b.y - b.x * a.y)
+        .abs()
+}
+
+/// The number of integer points strictly inside a polygon with integer vertices, from twice
+/// its area and the number of integer points on its edges, by Pick's theorem.
+pub fn interior_points<T: Coord>(double_area: T, boundary: T) -> T {
+    (double_area - boundary) / (T::ONE + T::ONE) + T::ONE
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_pos() {
+        let a = Pos::new(1, 2);
+        let b = Pos::new(4, -2);
+        assert_eq!(a + b, Pos::new(5, 0));
+        assert_eq!(b - a, Pos::new(3, -4));
+        assert_eq!(-(a * 3), Pos::new(-3, -6));
+        assert_eq!(a.manhattan(&b), 7);
+        assert_eq!(a.chebyshev(&b), 4);
+        assert_eq!(a.step(Dir::Up), Pos::new(1, 1));
+        assert_eq!(a.neighbours8().count(), 8);
+        assert!(a.neighbours8().all(|n| n.chebyshev(&a) == 1));
+
+        assert_eq!(Pos::new(3_i32, 4).to_grid(), Some((3, 4)));
+        assert_eq!(Pos::new(-1_i32, 4).to_grid(), None);
+        assert_eq!(Pos::<i32>::from_grid((3, 4)), Pos::new(3, 4));
+
+        let c = Pos3::new(1, 2, 3);
+        assert_eq!(c.manhattan(&Pos3::default()), 6);
+        assert_eq!(c.chebyshev(&Pos3::new(0, 0, 0)), 3);
+    }
+
+    #[test]
+    fn test_dir() {
+        assert_eq!(Dir::Up.turn_right(), Dir::Right);
+        assert_eq!(Dir::Up.turn_left(), Dir::Left);
+        assert_eq!(Dir::Left.reverse(), Dir::Right);
+        for dir in Dir::ALL {
+            assert_eq!(dir.turn_left().turn_right(), dir);
+            assert_eq!(Dir::from_delta(dir.delta::<i32>()), Some(dir));
+            assert_eq!(dir.delta::<i64>() + dir.reverse().delta(), Pos::default());
+        }
+        assert_eq!(Dir::from_delta(Pos::new(1, 1)), None);
+    }
+
+    #[test]
+    fn test_polygon() {
+        let square = [(0, 0), (4, 0), (4, 4), (0, 4)].map(|(x, y)| Pos::new(x, y));
+        assert_eq!(double_area(&square), 32);
+        assert_eq!(interior_points(32, 16), 9);
+
+        let mut reversed = square;
+        reversed.reverse();
+        assert_eq!(double_area(&reversed), 32);
+
+        // A triangle with half an integer area.
+        let triangle = [(0, 0), (3, 0), (0, 3)].map(|(x, y)| Pos::new(x, y));
+        assert_eq!(double_area(&triangle), 9);
+        assert_eq!(interior_points(9, 9), 1);
+        assert_eq!(double_area::<i32>(&[]), 0);
+    }
+}
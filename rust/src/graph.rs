@@ -0,0 +1,403 @@
+//! Graph searches over implicit graphs: nodes are any `Copy + Eq + Hash` value and edges come
+//! from a neighbour function, so grids, state machines and adjacency maps all work the same.
+
+use std::cmp::Ordering;
+use std::collections::hash_map::Entry;
+use std::collections::{BinaryHeap, HashMap, HashSet, VecDeque};
+use std::hash::Hash;
+use std::ops::Add;
+
+/// The result of a search: the distance to every reached node, and the node it was reached
+/// from, for path reconstruction.
+#[derive(Debug, Clone)]
+pub struct Paths<N, C = usize> {
+    pub dist: HashMap<N, C>,
+    pub prev: HashMap<N, N>,
+}
+
+impl<N: Copy + Eq + Hash, C: Copy> Paths<N, C> {
+    fn new() -> Self {
+        Self {
+            dist: HashMap::new(),
+            prev: HashMap::new(),
+        }
+    }
+
+    pub fn distance(&self, node: N) -> Option<C> {
+        self.dist.get(&node).copied()
+    }
+
+    /// The nodes from a start node up to and including `node`, if it was reached.
+    pub fn path_to(&self, node: N) -> Option<Vec<N>> {
+        self.dist.get(&node)?;
+
+        let mut path = vec![node];
+        let mut curr = node;
+        while let Some(&prev) = self.prev.get(&curr) {
+            path.push(prev);
+            curr = prev;
+        }
+        path.reverse();
+        Some(path)
+    }
+}
+
+/// Breadth-first search from all `starts`, counting steps.
+pub fn bfs<N, I>(
+    starts: impl IntoIterator<Item = N>,
+    mut neighbours: impl FnMut(N) -> I,
+) -> Paths<N>
+where
+    N: Copy + Eq + Hash,
+    I: IntoIterator<Item = N>,
+{
+    let mut paths = Paths::new();
+    let mut queue = VecDeque::new();
+    for start in starts {
+        if paths.dist.insert(start, 0).is_none() {
+            queue.push_back(start);
+        }
+    }
+
+    while let Some(node) = queue.pop_front() {
+        let dist = paths.dist[&node] + 1;
+        for next in neighbours(node) {
+            if let Entry::Vacant(entry) = paths.dist.entry(next) {
+                entry.insert(dist);
+                paths.prev.insert(next, node);
+                queue.push_back(next);
+            }
+        }
+    }
+
+    paths
+}
+
+/// The nodes reachable from `starts`, the starts included.
+pub fn flood_fill<N, I>(
+    starts: impl IntoIterator<Item = N>,
+    mut neighbours: impl FnMut(N) -> I,
+) -> HashSet<N>
+where
+    N: Copy + Eq + Hash,
+    I: IntoIterator<Item = N>,
+{
+    let mut seen = HashSet::new();
+    let mut stack = starts
+        .into_iter()
+        .filter(|&start| seen.insert(start))
+        .collect::<Vec<_>>();
+
+    while let Some(node) = stack.pop() {
+        for next in neighbours(node) {
+            if seen.insert(next) {
+                stack.push(next);
+            }
+        }
+    }
+
+    seen
+}
+
+/// Groups `nodes` into connected components. The neighbour function is expected to be
+/// symmetric; components come out in order of their first node.
+pub fn components<N, I>(
+    nodes: impl IntoIterator<Item = N>,
+    mut neighbours: impl FnMut(N) -> I,
+) -> Vec<HashSet<N>>
+where
+    N: Copy + Eq + Hash,
+    I: IntoIterator<Item = N>,
+{
+    let mut seen = HashSet::new();
+    let mut components = vec![];
+
+    for node in nodes {
+        if seen.contains(&node) {
+            continue;
+        }
+        let component = flood_fill([node], &mut neighbours);
+        seen.extend(component.iter().copied());
+        components.push(component);
+    }
+
+    components
+}
+
+/// A node in the priority queue, ordered by priority only so nodes need not be `Ord`.
+struct Queued<N, C> {
+    priority: C,
+    cost: C,
+    node: N,
+}
+
+impl<N, C: Ord> PartialEq for Queued<N, C> {
+    fn eq(&self, other: &Self) -> bool {
+        self.priority == other.priority
+    }
+}
+
+impl<N, C: Ord> Eq for Queued<N, C> {}
+
+impl<N, C: Ord> PartialOrd for Queued<N, C> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<N, C: Ord> Ord for Queued<N, C> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        other.priority.cmp(&self.priority)
+    }
+}
+
+/// Dijkstra's shortest paths from all `starts` to every reachable node.
+pub fn dijkstra<N, C, I>(
+    starts: impl IntoIterator<Item = N>,
+    neighbours: impl FnMut(N) -> I,
+) -> Paths<N, C>
+where
+    N: Copy + Eq + Hash,
+    C: Copy + Ord + Default + Add<Output = C>,
+    I: IntoIterator<Item = (N, C)>,
+{
+    search(starts, neighbours, |_| C::default(), |_| false).1
+}
+
+/// A* search from all `starts` until a node satisfying `is_goal` is settled.
+///
+/// Returns the goal reached, if any, with the paths found so far. `heuristic` must never
+/// overestimate the remaining cost, or the result may not be the shortest. It need not be
+/// consistent: a node reached again at a lower cost is expanded again.
+pub fn astar<N, C, I>(
+    starts: impl IntoIterator<Item = N>,
+    neighbours: impl FnMut(N) -> I,
+    heuristic: impl FnMut(N) -> C,
+    is_goal: impl FnMut(N) -> bool,
+) -> (Option<N>, Paths<N, C>)
+where
+    N: Copy + Eq + Hash,
+    C: Copy + Ord + Default + Add<Output = C>,
+    I: IntoIterator<Item = (N, C)>,
+{
+    search(starts, neighbours, heuristic, is_goal)
+}
+
+/// Dijkstra's search stopping at the first node satisfying `is_goal`.
+pub fn dijkstra_to<N, C, I>(
+    starts: impl IntoIterator<Item = N>,
+    neighbours: impl FnMut(N) -> I,
+    is_goal: impl FnMut(N) -> bool,
+) -> (Option<N>, Paths<N, C>)
+where
+    N: Copy + Eq + Hash,
+    C: Copy + Ord + Default + Add<Output = C>,
+    I: IntoIterator<Item = (N, C)>,
+{
+    search(starts, neighbours, |_| C::default(), is_goal)
+}
+
+fn search<N, C, I>(
+    starts: impl IntoIterator<Item = N>,
+    mut neighbours: impl FnMut(N) -> I,
+    mut heuristic: impl FnMut(N) -> C,
+    mut is_goal: impl FnMut(N) -> bool,
+) -> (Option<N>, Paths<N, C>)
+where
+    N: Copy + Eq + Hash,
+    C: Copy + Ord + Default + Add<Output = C>,
+    I: IntoIterator<Item = (N, C)>,
+{
+    let mut paths = Paths::new();
+    let mut heap = BinaryHeap::new();
+
+    for node in starts {
+        paths.dist.insert(node, C::default());
+        heap.push(Queued {
+            priority: heuristic(node),
+            cost: C::default(),
+            node,
+        });
+    }
+
+    while let Some(Queued { cost, node, .. }) = heap.pop() {
+        // Skip entries for nodes that were reached at a lower cost since they were queued.
+        if paths.dist.get(&node).is_some_and(|&d| d < cost) {
+            continue;
+        }
+        if is_goal(node) {
+            return (Some(node), paths);
+        }
+
+        for (next, weight) in neighbours(node) {
+            let next_cost = cost + weight;
+            if paths.dist.get(&next).is_some_and(|&d| d <= next_cost) {
+                continue;
+            }
+
+            paths.dist.insert(next, next_cost);
+            paths.prev.insert(next, node);
+            heap.push(Queued {
+                priority: next_cost + heuristic(next),
+                cost: next_cost,
+                node: next,
+            });
+        }
+    }
+
+    (None, paths)
+}
+
+/// Orders `nodes` and everything reachable from them so that every node comes before its
+/// successors. Fails with the nodes of a cycle if there is one.
+pub fn toposort<N, I>(
+    nodes: impl IntoIterator<Item = N>,
+    mut successors: impl FnMut(N) -> I,
+) -> Result<Vec<N>, Vec<N>>
+where
+    N: Copy + Eq + Hash,
+    I: IntoIterator<Item = N>,
+{
+    #[derive(PartialEq)]
+    enum Mark {
+        Active,
+        Done,
+    }
+
+    let mut marks = HashMap::new();
+    let mut order = vec![];
+
+    for root in nodes {
+        if marks.contains_key(&root) {
+            continue;
+        }
+        marks.insert(root, Mark::Active);
+        let mut stack = vec![(root, successors(root).into_iter())];
+
+        while let Some((node, iter)) = stack.last_mut() {
+            let node = *node;
+            match iter.next() {
+                Some(next) => match marks.get(&next) {
+                    None => {
+                        marks.insert(next, Mark::Active);
+                        stack.push((next, successors(next).into_iter()));
+                    }
+                    Some(Mark::Active) => {
+                        let from = stack.iter().position(|(n, _)| *n == next).unwrap_or(0);
+                        return Err(stack[from..].iter().map(|(n, _)| *n).collect());
+                    }
+                    Some(Mark::Done) => {}
+                },
+                None => {
+                    marks.insert(node, Mark::Done);
+                    order.push(node);
+                    stack.pop();
+                }
+            }
+        }
+    }
+
+    order.reverse();
+    Ok(order)
+}
+
+/// A cycle reachable from `nodes`, in walking order, if there is one.
+pub fn find_cycle<N, I>(
+    nodes: impl IntoIterator<Item = N>,
+    successors: impl FnMut(N) -> I,
+) -> Option<Vec<N>>
+where
+    N: Copy + Eq + Hash,
+    I: IntoIterator<Item = N>,
+{
+    toposort(nodes, successors).err()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const MAZE: [&str; 4] = ["..#.", ".##.", "...#", "#.#."];
+
+    fn open((x, y): (i32, i32)) -> bool {
+        (0..4).contains(&x)
+            && (0..4).contains(&y)
+            && MAZE[y as usize].as_bytes()[x as usize] == b'.'
+    }
+
+    fn steps((x, y): (i32, i32)) -> impl Iterator<Item = (i32, i32)> {
+        [(x + 1, y), (x - 1, y), (x, y + 1), (x, y - 1)]
+            .into_iter()
+            .filter(|&p| open(p))
+    }
+
+    #[test]
+    fn test_bfs() {
+        let paths = bfs([(0, 0)], steps);
+        assert_eq!(paths.distance((1, 3)), Some(4));
+        assert_eq!(paths.distance((3, 0)), None);
+        assert_eq!(
+            paths.path_to((1, 3)).unwrap(),
+            [(0, 0), (0, 1), (0, 2), (1, 2), (1, 3)]
+        );
+
+        assert_eq!(flood_fill([(0, 0)], steps).len(), 7);
+        let cells = (0..4).flat_map(|y| (0..4).map(move |x| (x, y)));
+        let sizes = components(cells.filter(|&p| open(p)), steps)
+            .iter()
+            .map(HashSet::len)
+            .collect::<Vec<_>>();
+        assert_eq!(sizes, [7, 2, 1]);
+    }
+
+    #[test]
+    fn test_weighted() {
+        // Going down costs 10, everything else 1.
+        let weighted = |(x, y): (i32, i32)| {
+            steps((x, y)).map(move |(nx, ny)| ((nx, ny), if ny > y { 10 } else { 1 }))
+        };
+        let paths = dijkstra([(0, 0)], weighted);
+        assert_eq!(paths.distance((1, 3)), Some(31));
+
+        let goal = (2, 2);
+        let (found, paths) = astar(
+            [(0, 0)],
+            weighted,
+            |(x, y): (i32, i32)| (goal.0 - x).abs() + (goal.1 - y).abs(),
+            |node| node == goal,
+        );
+        assert_eq!(found, Some(goal));
+        assert_eq!(paths.distance(goal), Some(22));
+        assert_eq!(dijkstra_to([(0, 0)], weighted, |n| n == (3, 3)).0, None);
+    }
+
+    #[test]
+    fn test_astar_inconsistent() {
+        // The heuristic never overestimates, but drops by more than the edge from A to C, so
+        // C is first expanded at cost 4 through B and has to be expanded again at cost 2.
+        let edges = |node| match node {
+            'S' => vec![('A', 1), ('B', 1)],
+            'A' => vec![('C', 1)],
+            'B' => vec![('C', 3)],
+            'C' => vec![('G', 10)],
+            _ => vec![],
+        };
+        let heuristic = |node| if node == 'A' { 10 } else { 0 };
+
+        let (found, paths) = astar(['S'], edges, heuristic, |node| node == 'G');
+        assert_eq!(found, Some('G'));
+        assert_eq!(paths.distance('G'), Some(12));
+        assert_eq!(paths.path_to('G').unwrap(), ['S', 'A', 'C', 'G']);
+    }
+
+    #[test]
+    fn test_toposort() {
+        let edges = HashMap::from([(1, vec![2, 3]), (2, vec![4]), (3, vec![4]), (4, vec![])]);
+        let order = toposort([1], |n| edges[&n].clone()).unwrap();
+        assert_eq!((order[0], order[3]), (1, 4));
+
+        let cyclic = HashMap::from([(1, vec![2]), (2, vec![3]), (3, vec![1, 4]), (4, vec![])]);
+        let cycle = find_cycle([4, 1], |n| cyclic[&n].clone()).unwrap();
+        assert_eq!(cycle, [1, 2, 3]);
+    }
+}
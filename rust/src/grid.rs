@@ -0,0 +1,319 @@
+//! A rectangular grid stored row by row in one contiguous `Vec`, for the many puzzles whose
+//! input is a map of characters.
+
+use std::fmt;
+use std::ops::{Index, IndexMut};
+use std::str::FromStr;
+
+use crate::error::ParseError;
+use crate::geometry::{Coord, Pos};
+
+const DELTAS4: [(isize, isize); 4] = [(1, 0), (-1, 0), (0, 1), (0, -1)];
+const DELTAS8: [(isize, isize); 8] = [
+    (1, 0),
+    (1, -1),
+    (0, -1),
+    (-1, -1),
+    (-1, 0),
+    (-1, 1),
+    (0, 1),
+    (1, 1),
+];
+
+/// Positions are `(x, y)` with `x` being the column and `y` the row, starting top left.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+impl<T> Grid<T> {
+    pub fn new(width: usize, height: usize, cells: Vec<T>) -> Self {
+        assert_eq!(width * height, cells.len(), "Grid cells don't match size");
+        Self {
+            width,
+            height,
+            cells,
+        }
+    }
+
+    pub fn filled(width: usize, height: usize, value: T) -> Self
+    where
+        T: Clone,
+    {
+        Self::new(width, height, vec![value; width * height])
+    }
+
+    /// Parses one row per line, converting each character with `f`. All lines must be as long
+    /// as the first.
+    pub fn parse_with<E>(
+        input: &str,
+        mut f: impl FnMut(char) -> Result<T, E>,
+    ) -> Result<Self, ParseError>
+    where
+        E: fmt::Display,
+    {
+        let mut width = None;
+        let mut height = 0;
+        let mut cells = vec![];
+
+        for (y, line) in input.lines().enumerate() {
+            let mut len = 0;
+            for (i, c) in line.char_indices() {
+                let cell = f(c).map_err(|e| {
+                    ParseError::new(line, &line[i..(i + c.len_utf8())], e.to_string())
+                        .on_line(y + 1)
+                })?;
+                cells.push(cell);
+                len += 1;
+            }
+
+            match width {
+                None => width = Some(len),
+                Some(width) if width != len => {
+                    return Err(ParseError::new(
+                        line,
+                        line,
+                        format!("expected a row of length {width} but found {len}"),
+                    )
+                    .on_line(y + 1))
+                }
+                _ => {}
+            }
+            height += 1;
+        }
+
+        Ok(Self::new(width.unwrap_or_default(), height, cells))
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn get(&self, x: usize, y: usize) -> Option<&T> {
+        (x < self.width && y < self.height).then(|| &self.cells[y * self.width + x])
+    }
+
+    pub fn get_mut(&mut self, x: usize, y: usize) -> Option<&mut T> {
+        (x < self.width && y < self.height).then(|| &mut self.cells[y * self.width + x])
+    }
+
+    pub fn get_pos<C: Coord>(&self, pos: Pos<C>) -> Option<&T> {
+        let (x, y) = pos.to_grid()?;
+        self.get(x, y)
+    }
+
+    pub fn get_pos_mut<C: Coord>(&mut self, pos: Pos<C>) -> Option<&mut T> {
+        let (x, y) = pos.to_grid()?;
+        self.get_mut(x, y)
+    }
+
+    /// Converts a signed position into one within the grid, if it is within.
+    pub fn checked(&self, x: isize, y: isize) -> Option<(usize, usize)> {
+        (x >= 0 && y >= 0 && (x as usize) < self.width && (y as usize) < self.height)
+            .then_some((x as usize, y as usize))
+    }
+
+    pub fn row(&self, y: usize) -> &[T] {
+        &self.cells[(y * self.width)..((y + 1) * self.width)]
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> + '_ {
+        (0..self.height).map(|y| self.row(y))
+    }
+
+    pub fn column(&self, x: usize) -> impl Iterator<Item = &T> + '_ {
+        assert!(x < self.width, "Column out of bounds");
+        self.cells.iter().skip(x).step_by(self.width)
+    }
+
+    pub fn columns(&self) -> impl Iterator<Item = impl Iterator<Item = &T> + '_> + '_ {
+        (0..self.width).map(|x| self.column(x))
+    }
+
+    /// All positions, row by row.
+    pub fn positions(&self) -> impl Iterator<Item = (usize, usize)> {
+        let width = self.width;
+        (0..self.height).flat_map(move |y| (0..width).map(move |x| (x, y)))
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = ((usize, usize), &T)> + '_ {
+        self.positions().zip(self.cells.iter())
+    }
+
+    /// The position of the first cell, row by row, matching `pred`.
+    pub fn find(&self, mut pred: impl FnMut(&T) -> bool) -> Option<(usize, usize)> {
+        self.iter().find(|(_, c)| pred(c)).map(|(p, _)| p)
+    }
+
+    /// Positions right, left, below and above that are within the grid.
+    pub fn neighbours4(&self, x: usize, y: usize) -> impl Iterator<Item = (usize, usize)> + '_ {
+        self.neighbours(x, y, &DELTAS4)
+    }
+
+    /// Positions around, diagonals included, that are within the grid.
+    pub fn neighbours8(&self, x: usize, y: usize) -> impl Iterator<Item = (usize, usize)> + '_ {
+        self.neighbours(x, y, &DELTAS8)
+    }
+
+    fn neighbours<'a>(
+        &'a self,
+        x: usize,
+        y: usize,
+        deltas: &'static [(isize, isize)],
+    ) -> impl Iterator<Item = (usize, usize)> + 'a {
+        deltas
+            .iter()
+            .filter_map(move |&(dx, dy)| self.checked(x as isize + dx, y as isize + dy))
+    }
+
+    pub fn map<U>(&self, f: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid::new(self.width, self.height, self.cells.iter().map(f).collect())
+    }
+
+    fn from_fn(width: usize, height: usize, mut f: impl FnMut(usize, usize) -> T) -> Self {
+        let cells = (0..height)
+            .flat_map(|y| (0..width).map(move |x| (x, y)))
+            .map(|(x, y)| f(x, y))
+            .collect();
+        Self::new(width, height, cells)
+    }
+}
+
+impl<T: Clone> Grid<T> {
+    /// Mirrors along the diagonal, so rows become columns.
+    pub fn transpose(&self) -> Self {
+        Self::from_fn(self.height, self.width, |x, y| self[(y, x)].clone())
+    }
+
+    pub fn rotate_clockwise(&self) -> Self {
+        Self::from_fn(self.height, self.width, |x, y| {
+            self[(y, self.height - 1 - x)].clone()
+        })
+    }
+
+    pub fn rotate_counter_clockwise(&self) -> Self {
+        Self::from_fn(self.height, self.width, |x, y| {
+            self[(self.width - 1 - y, x)].clone()
+        })
+    }
+
+    /// Mirrors left to right.
+    pub fn flip_horizontal(&self) -> Self {
+        Self::from_fn(self.width, self.height, |x, y| {
+            self[(self.width - 1 - x, y)].clone()
+        })
+    }
+
+    /// Mirrors top to bottom.
+    pub fn flip_vertical(&self) -> Self {
+        Self::from_fn(self.width, self.height, |x, y| {
+            self[(x, self.height - 1 - y)].clone()
+        })
+    }
+}
+
+impl<T> Index<(usize, usize)> for Grid<T> {
+    type Output = T;
+    fn index(&self, (x, y): (usize, usize)) -> &T {
+        self.get(x, y).expect("Position out of grid")
+    }
+}
+
+impl<T> IndexMut<(usize, usize)> for Grid<T> {
+    fn index_mut(&mut self, (x, y): (usize, usize)) -> &mut T {
+        self.get_mut(x, y).expect("Position out of grid")
+    }
+}
+
+impl<T, C: Coord> Index<Pos<C>> for Grid<T> {
+    type Output = T;
+    fn index(&self, pos: Pos<C>) -> &T {
+        self.get_pos(pos).expect("Position out of grid")
+    }
+}
+
+impl<T, C: Coord> IndexMut<Pos<C>> for Grid<T> {
+    fn index_mut(&mut self, pos: Pos<C>) -> &mut T {
+        self.get_pos_mut(pos).expect("Position out of grid")
+    }
+}
+
+impl FromStr for Grid<char> {
+    type Err = ParseError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::parse_with(s, Ok::<_, ParseError>)
+    }
+}
+
+impl<T: fmt::Display> fmt::Display for Grid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (y, row) in self.rows().enumerate() {
+            if y > 0 {
+                writeln!(f)?;
+            }
+            for cell in row {
+                write!(f, "{cell}")?;
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const INPUT: &str = "abc\ndef";
+
+    #[test]
+    fn test_parse() -> Result<(), ParseError> {
+        let grid = INPUT.parse::<Grid<char>>()?;
+        assert_eq!((grid.width(), grid.height()), (3, 2));
+        assert_eq!(grid[(1, 1)], 'e');
+        assert_eq!(grid.get(3, 0), None);
+        assert_eq!(grid.to_string(), INPUT);
+
+        let err = "abc\nde\nfgh".parse::<Grid<char>>().unwrap_err();
+        assert_eq!(err.line, 2);
+
+        let digits = Grid::parse_with("12\n3x", |c| c.to_digit(10).ok_or("not a digit"));
+        assert_eq!((digits.unwrap_err().column), 2);
+        Ok(())
+    }
+
+    #[test]
+    fn test_views() -> Result<(), ParseError> {
+        let grid = INPUT.parse::<Grid<char>>()?;
+        assert_eq!(grid.row(1), &['d', 'e', 'f']);
+        assert_eq!(grid.column(2).collect::<String>(), "cf");
+        assert_eq!(grid.columns().count(), 3);
+        assert_eq!(grid.find(|&c| c == 'e'), Some((1, 1)));
+        assert_eq!(grid.checked(-1, 0), None);
+        assert_eq!(grid[Pos::new(2, 1)], 'f');
+        assert_eq!(grid.get_pos(Pos::new(-1, 1)), None);
+
+        let mut n4 = grid.neighbours4(0, 0).collect::<Vec<_>>();
+        n4.sort();
+        assert_eq!(n4, vec![(0, 1), (1, 0)]);
+        assert_eq!(grid.neighbours8(1, 0).count(), 5);
+        Ok(())
+    }
+
+    #[test]
+    fn test_transforms() -> Result<(), ParseError> {
+        let grid = INPUT.parse::<Grid<char>>()?;
+        assert_eq!(grid.transpose().to_string(), "ad\nbe\ncf");
+        assert_eq!(grid.rotate_clockwise().to_string(), "da\neb\nfc");
+        assert_eq!(grid.rotate_counter_clockwise().to_string(), "cf\nbe\nad");
+        assert_eq!(grid.flip_horizontal().to_string(), "cba\nfed");
+        assert_eq!(grid.flip_vertical().to_string(), "def\nabc");
+        assert_eq!(grid.rotate_clockwise().rotate_counter_clockwise(), grid);
+        Ok(())
+    }
+}
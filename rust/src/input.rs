@@ -0,0 +1,110 @@
+//! Loading of puzzle inputs from files or stdin, failing on read errors instead of solving a
+//! truncated input.
+
+use std::fmt;
+use std::fs;
+use std::io::{self, Read};
+use std::path::PathBuf;
+use std::sync::OnceLock;
+
+use anyhow::{anyhow, Context, Result};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Source {
+    Stdin,
+    File(PathBuf),
+}
+
+impl Source {
+    /// `../input/dayNN`, relative to the `rust` directory the runner is started from.
+    pub fn default_for(day: u8) -> Self {
+        Source::File(PathBuf::from(format!("../input/day{day:02}")))
+    }
+
+    /// Whether there's an input to read, which isn't the case for a missing or empty file.
+    pub fn has_input(&self) -> bool {
+        match self {
+            Source::Stdin => true,
+            Source::File(path) => path.metadata().is_ok_and(|m| m.len() > 0),
+        }
+    }
+
+    /// Reads the whole input. Stdin is only read once, later reads get the same content.
+    pub fn read(&self) -> Result<String> {
+        match self {
+            Source::Stdin => stdin(),
+            Source::File(path) => {
+                let bytes =
+                    fs::read(path).with_context(|| format!("Unable to read {}", path.display()))?;
+                decode(&self.to_string(), bytes)
+            }
+        }
+    }
+}
+
+impl From<&str> for Source {
+    /// `-` means stdin, anything else is a path.
+    fn from(s: &str) -> Self {
+        if s == "-" {
+            Source::Stdin
+        } else {
+            Source::File(PathBuf::from(s))
+        }
+    }
+}
+
+impl fmt::Display for Source {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Source::Stdin => write!(f, "stdin"),
+            Source::File(path) => write!(f, "{}", path.display()),
+        }
+    }
+}
+
+fn stdin() -> Result<String> {
+    static STDIN: OnceLock<Result<String, String>> = OnceLock::new();
+
+    STDIN
+        .get_or_init(|| {
+            let mut bytes = vec![];
+            io::stdin()
+                .read_to_end(&mut bytes)
+                .map_err(|e| format!("Unable to read stdin: {e}"))?;
+            decode("stdin", bytes).map_err(|e| e.to_string())
+        })
+        .clone()
+        .map_err(|e| anyhow!(e))
+}
+
+fn decode(name: &str, bytes: Vec<u8>) -> Result<String> {
+    String::from_utf8(bytes).map_err(|e| {
+        let valid = e.utf8_error().valid_up_to();
+        let line = e.as_bytes()[..valid]
+            .iter()
+            .filter(|&&b| b == b'\n')
+            .count()
+            + 1;
+        anyhow!("{name} is not valid UTF-8 on line {line} (byte {valid})")
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_decode() {
+        assert_eq!(decode("in", b"ab\ncd".to_vec()).unwrap(), "ab\ncd");
+
+        let err = decode("in", b"ab\nc\xffd".to_vec()).unwrap_err();
+        assert_eq!(err.to_string(), "in is not valid UTF-8 on line 2 (byte 4)");
+    }
+
+    #[test]
+    fn test_source() {
+        assert_eq!(Source::from("-"), Source::Stdin);
+        assert_eq!(Source::default_for(5).to_string(), "../input/day05");
+        assert!(Source::from("../input/day00").read().is_err());
+    }
+}
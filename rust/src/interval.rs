@@ -0,0 +1,376 @@
+//! Integer intervals and sets of them, for puzzles that push whole ranges of values through
+//! a transformation instead of single values.
+
+use std::fmt;
+use std::ops::{Range, RangeInclusive};
+
+use crate::math::Integer;
+
+/// The half-open interval `start..end`. It is empty when `end <= start`.
+///
+/// Closed intervals are built with `closed` and read back with `last`.
+#[derive(Debug, Default, Copy, Clone, Eq, PartialEq, Hash, PartialOrd, Ord)]
+pub struct Interval<T = i64> {
+    pub start: T,
+    pub end: T,
+}
+
+/// The parts of an interval before, inside and after a divider.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Split<T = i64> {
+    pub before: Option<Interval<T>>,
+    pub inside: Option<Interval<T>>,
+    pub after: Option<Interval<T>>,
+}
+
+impl<T: Integer> Split<T> {
+    /// The parts outside the divider, in order.
+    pub fn outside(&self) -> impl Iterator<Item = Interval<T>> {
+        self.before.into_iter().chain(self.after)
+    }
+}
+
+impl<T: Integer> Interval<T> {
+    pub const fn new(start: T, end: T) -> Self {
+        Self { start, end }
+    }
+
+    /// The interval `first..=last`.
+    pub fn closed(first: T, last: T) -> Self {
+        Self::new(first, last + T::ONE)
+    }
+
+    pub fn with_len(start: T, len: T) -> Self {
+        Self::new(start, start + len)
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.end <= self.start
+    }
+
+    pub fn len(&self) -> T {
+        if self.is_empty() {
+            T::ZERO
+        } else {
+            self.end - self.start
+        }
+    }
+
+    /// The largest value inside, if any.
+    pub fn last(&self) -> Option<T> {
+        (!self.is_empty()).then(|| self.end - T::ONE)
+    }
+
+    pub fn contains(&self, v: T) -> bool {
+        self.start <= v && v < self.end
+    }
+
+    /// Whether the intervals share at least one value.
+    pub fn overlaps(&self, other: &Self) -> bool {
+        self.intersection(other).is_some()
+    }
+
+    /// Whether the intervals overlap or are directly adjacent, so their union is one interval.
+    pub fn touches(&self, other: &Self) -> bool {
+        !self.is_empty() && !other.is_empty() && self.start <= other.end && other.start <= self.end
+    }
+
+    pub fn intersection(&self, other: &Self) -> Option<Self> {
+        let result = Self::new(self.start.max(other.start), self.end.min(other.end));
+        (!result.is_empty()).then_some(result)
+    }
+
+    /// The single interval covering both, if they touch.
+    pub fn union(&self, other: &Self) -> Option<Self> {
+        self.touches(other)
+            .then(|| Self::new(self.start.min(other.start), self.end.max(other.end)))
+    }
+
+    /// The values not in `other`, at most two intervals.
+    pub fn difference(&self, other: &Self) -> impl Iterator<Item = Self> {
+        self.split(other).outside()
+    }
+
+    /// Splits into the parts before, inside and after `divider`. Empty parts are `None`.
+    pub fn split(&self, divider: &Self) -> Split<T> {
+        if self.is_empty() || divider.is_empty() {
+            return Split {
+                before: (!self.is_empty()).then_some(*self),
+                inside: None,
+                after: None,
+            };
+        }
+
+        let part = |start: T, end: T| {
+            let part = Self::new(start.max(self.start), end.min(self.end));
+            (!part.is_empty()).then_some(part)
+        };
+
+        Split {
+            before: part(self.start, divider.start),
+            inside: self.intersection(divider),
+            after: part(divider.end, self.end),
+        }
+    }
+
+    /// The interval moved by `offset`.
+    pub fn shift(&self, offset: T) -> Self {
+        Self::new(self.start + offset, self.end + offset)
+    }
+}
+
+impl<T: Integer> From<Range<T>> for Interval<T> {
+    fn from(range: Range<T>) -> Self {
+        Self::new(range.start, range.end)
+    }
+}
+
+impl<T: Integer> From<RangeInclusive<T>> for Interval<T> {
+    fn from(range: RangeInclusive<T>) -> Self {
+        Self::closed(*range.start(), *range.end())
+    }
+}
+
+impl<T: fmt::Display> fmt::Display for Interval<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "[{}, {})", self.start, self.end)
+    }
+}
+
+/// A set of values stored as sorted, disjoint and non-adjacent intervals.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct IntervalSet<T = i64> {
+    intervals: Vec<Interval<T>>,
+}
+
+impl<T: Integer> Default for IntervalSet<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: Integer> IntervalSet<T> {
+    pub const fn new() -> Self {
+        Self { intervals: vec![] }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.intervals.is_empty()
+    }
+
+    /// The number of values in the set.
+    pub fn len(&self) -> T {
+        self.intervals
+            .iter()
+            .fold(T::ZERO, |acc, interval| acc + interval.len())
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &Interval<T>> {
+        self.intervals.iter()
+    }
+
+    pub fn min(&self) -> Option<T> {
+        self.intervals.first().map(|interval| interval.start)
+    }
+
+    pub fn max(&self) -> Option<T> {
+        self.intervals.last().and_then(Interval::last)
+    }
+
+    pub fn contains(&self, v: T) -> bool {
+        let i = self.intervals.partition_point(|interval| interval.end <= v);
+        self.intervals
+            .get(i)
+            .is_some_and(|interval| interval.contains(v))
+    }
+
+    /// Adds the values of `interval`, coalescing it with the intervals it touches.
+    pub fn insert(&mut self, interval: Interval<T>) {
+        if interval.is_empty() {
+            return;
+        }
+
+        let from = self.intervals.partition_point(|i| i.end < interval.start);
+        let to = self.intervals.partition_point(|i| i.start <= interval.end);
+        let merged = self.intervals[from..to]
+            .iter()
+            .fold(interval, |acc, i| acc.union(i).unwrap_or(acc));
+        self.intervals.splice(from..to, [merged]);
+    }
+
+    /// Takes the values of `interval` out of the set.
+    pub fn remove(&mut self, interval: Interval<T>) {
+        if interval.is_empty() {
+            return;
+        }
+
+        let from = self.intervals.partition_point(|i| i.end <= interval.start);
+        let to = self.intervals.partition_point(|i| i.start < interval.end);
+        let rest = self.intervals[from..to]
+            .iter()
+            .flat_map(|i| i.difference(&interval))
+            .collect::<Vec<_>>();
+        self.intervals.splice(from..to, rest);
+    }
+
+    pub fn union(&self, other: &Self) -> Self {
+        let mut result = self.clone();
+        for &interval in &other.intervals {
+            result.insert(interval);
+        }
+        result
+    }
+
+    pub fn intersection(&self, other: &Self) -> Self {
+        let mut intervals = vec![];
+        let (mut a, mut b) = (self.intervals.iter(), other.intervals.iter());
+        let (mut x, mut y) = (a.next(), b.next());
+
+        while let (Some(i), Some(j)) = (x, y) {
+            intervals.extend(i.intersection(j));
+            if i.end < j.end {
+                x = a.next();
+            } else {
+                y = b.next();
+            }
+        }
+
+        Self { intervals }
+    }
+
+    pub fn difference(&self, other: &Self) -> Self {
+        let mut result = self.clone();
+        for &interval in &other.intervals {
+            result.remove(interval);
+        }
+        result
+    }
+
+    /// Splits into the values inside `divider` and those outside of it.
+    pub fn split(&self, divider: &Interval<T>) -> (Self, Self) {
+        let divider = Self::from(*divider);
+        (self.intersection(&divider), self.difference(&divider))
+    }
+
+    /// Every interval moved by `offset`.
+    pub fn shift(&self, offset: T) -> Self {
+        Self {
+            intervals: self.intervals.iter().map(|i| i.shift(offset)).collect(),
+        }
+    }
+}
+
+impl<T: Integer> From<Interval<T>> for IntervalSet<T> {
+    fn from(interval: Interval<T>) -> Self {
+        let mut set = Self::new();
+        set.insert(interval);
+        set
+    }
+}
+
+impl<T: Integer> FromIterator<Interval<T>> for IntervalSet<T> {
+    fn from_iter<I: IntoIterator<Item = Interval<T>>>(iter: I) -> Self {
+        let mut set = Self::new();
+        for interval in iter {
+            set.insert(interval);
+        }
+        set
+    }
+}
+
+impl<T: Integer> Extend<Interval<T>> for IntervalSet<T> {
+    fn extend<I: IntoIterator<Item = Interval<T>>>(&mut self, iter: I) {
+        for interval in iter {
+            self.insert(interval);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// An interval as `(start, len)`.
+    type Pair = (i64, i64);
+
+    fn split(range: Pair, divider: Pair) -> (Option<Pair>, Vec<Pair>) {
+        let as_pair = |i: Interval| (i.start, i.len());
+        let split =
+            Interval::with_len(range.0, range.1).split(&Interval::with_len(divider.0, divider.1));
+        (
+            split.inside.map(as_pair),
+            split.outside().map(as_pair).collect(),
+        )
+    }
+
+    #[test]
+    fn test_split() {
+        assert_eq!(split((1, 1), (5, 5)), (None, vec![(1, 1)]));
+        assert_eq!(split((1, 4), (5, 5)), (None, vec![(1, 4)]));
+        assert_eq!(split((1, 5), (5, 5)), (Some((5, 1)), vec![(1, 4)]));
+        assert_eq!(split((1, 6), (5, 5)), (Some((5, 2)), vec![(1, 4)]));
+        assert_eq!(split((5, 5), (5, 5)), (Some((5, 5)), vec![]));
+        assert_eq!(split((5, 6), (5, 5)), (Some((5, 5)), vec![(10, 1)]));
+        assert_eq!(split((6, 6), (5, 5)), (Some((6, 4)), vec![(10, 2)]));
+        assert_eq!(split((9, 1), (5, 5)), (Some((9, 1)), vec![]));
+        assert_eq!(split((9, 2), (5, 5)), (Some((9, 1)), vec![(10, 1)]));
+        assert_eq!(split((10, 1), (5, 5)), (None, vec![(10, 1)]));
+        assert_eq!(
+            split((1, 11), (5, 5)),
+            (Some((5, 5)), vec![(1, 4), (10, 2)])
+        );
+        assert_eq!(split((1, 0), (5, 5)), (None, vec![]));
+        assert_eq!(split((1, 3), (5, 0)), (None, vec![(1, 3)]));
+    }
+
+    #[test]
+    fn test_interval() {
+        let a = Interval::closed(1_u64, 5);
+        assert_eq!(a, Interval::from(1..6));
+        assert_eq!(a, Interval::from(1..=5));
+        assert_eq!((a.len(), a.last()), (5, Some(5)));
+        assert!(a.contains(5) && !a.contains(6));
+        assert_eq!(Interval::new(3_u64, 3).last(), None);
+
+        let b = Interval::new(6, 8);
+        assert!(!a.overlaps(&b) && a.touches(&b));
+        assert_eq!(a.union(&b), Some(Interval::new(1, 8)));
+        assert_eq!(a.union(&Interval::new(7, 8)), None);
+        assert_eq!(
+            a.intersection(&Interval::new(4, 10)),
+            Some(Interval::new(4, 6))
+        );
+        assert_eq!(a.to_string(), "[1, 6)");
+    }
+
+    #[test]
+    fn test_set() {
+        let mut set = [1..3, 5..7, 10..12]
+            .into_iter()
+            .map(Interval::from)
+            .collect::<IntervalSet>();
+        set.insert(Interval::new(3, 5));
+        assert_eq!(
+            set.iter().copied().collect::<Vec<_>>(),
+            [Interval::new(1, 7), Interval::new(10, 12)]
+        );
+        assert_eq!(set.len(), 8);
+        assert_eq!((set.min(), set.max()), (Some(1), Some(11)));
+        assert!(set.contains(6) && !set.contains(7) && set.contains(10));
+
+        let other = IntervalSet::from(Interval::new(5, 11));
+        assert_eq!(
+            set.intersection(&other).iter().copied().collect::<Vec<_>>(),
+            [Interval::new(5, 7), Interval::new(10, 11)]
+        );
+        assert_eq!(
+            set.difference(&other).iter().copied().collect::<Vec<_>>(),
+            [Interval::new(1, 5), Interval::new(11, 12)]
+        );
+        assert_eq!(set.union(&other), IntervalSet::from(Interval::new(1, 12)));
+
+        let (inside, outside) = set.split(&Interval::new(0, 2));
+        assert_eq!(inside, IntervalSet::from(Interval::new(1, 2)));
+        assert_eq!(outside.shift(-2).min(), Some(0));
+    }
+}
@@ -0,0 +1,36 @@
+//! Minimal helpers for writing JSON without pulling in a serialization framework.
+
+use std::fmt::Write;
+
+/// Quotes and escapes `s` as a JSON string.
+pub(crate) fn string(s: &str) -> String {
+    let mut res = String::with_capacity(s.len() + 2);
+    res.push('"');
+    for c in s.chars() {
+        match c {
+            '"' => res.push_str("\\\""),
+            '\\' => res.push_str("\\\\"),
+            '\n' => res.push_str("\\n"),
+            '\r' => res.push_str("\\r"),
+            '\t' => res.push_str("\\t"),
+            c if (c as u32) < 0x20 => {
+                let _ = write!(res, "\\u{:04x}", c as u32);
+            }
+            c => res.push(c),
+        }
+    }
+    res.push('"');
+    res
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_string() {
+        assert_eq!(string("abc"), r#""abc""#);
+        assert_eq!(string("a \"b\"\n\\"), r#""a \"b\"\n\\""#);
+        assert_eq!(string("\u{1}"), r#""\u0001""#);
+    }
+}
@@ -1,46 +1,85 @@
 extern crate time;
 
+use std::fmt;
 use std::time::*;
 
-pub fn measure<F, S, T>(f: F) -> Result<S, T>
-where
-    F: Fn() -> Result<S, T>,
-{
-    let start = Instant::now();
-    let mut _times = 100;
+pub mod answers;
+pub mod bench;
+pub mod cycle;
+pub mod days;
+pub mod error;
+pub mod geometry;
+pub mod graph;
+pub mod grid;
+pub mod input;
+pub mod interval;
+mod json;
+pub mod math;
+pub mod memo;
+pub mod parse;
+pub mod runner;
+pub mod scaffold;
+mod solution;
+pub mod viz;
 
-    #[cfg(not(feature = "timeit"))]
-    let res = f()?;
+pub use solution::{input_hash, Answers, Day, Parts, Run, Solution};
 
-    #[cfg(feature = "timeit")]
-    let mut res = f()?;
+/// Collects the time spent in each named phase of a run, e.g. reading, parsing and the parts.
+#[derive(Debug, Default, Clone)]
+pub struct Timer {
+    phases: Vec<(&'static str, Duration)>,
+}
 
-    #[cfg(feature = "timeit")]
-    {
+impl Timer {
+    pub fn phase<R>(&mut self, name: &'static str, f: impl FnOnce() -> R) -> R {
+        let start = Instant::now();
+        let res = f();
         let dur = start.elapsed();
-        if dur.as_nanos() > 500_000_000 {
-            _times /= 10;
-        } else if dur.as_nanos() < 500_000 {
-            _times *= 10;
-        }
-        for _ in 0..(_times - 1) {
-            res = f()?;
+
+        match self.phases.iter_mut().find(|(n, _)| *n == name) {
+            Some((_, total)) => *total += dur,
+            None => self.phases.push((name, dur)),
         }
+        res
     }
 
-    let dur = start.elapsed();
+    pub fn phases(&self) -> impl Iterator<Item = (&'static str, Duration)> + '_ {
+        self.phases.iter().cloned()
+    }
+}
 
-    #[cfg(feature = "timeit")]
-    {
-        println!(
-            "It took: {}ms on average for {} times",
-            (dur.as_nanos() / _times as u128) as f64 / 1_000_000.0,
-            _times
-        );
+impl fmt::Display for Timer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (i, (name, dur)) in self.phases().enumerate() {
+            if i > 0 {
+                write!(f, ", ")?;
+            }
+            write!(f, "{name}: {}ms", as_ms(dur))?;
+        }
+        Ok(())
     }
-    #[cfg(not(feature = "timeit"))]
-    {
-        println!("It took: {}ms", dur.as_nanos() as f64 / 1_000_000.0);
+}
+
+pub(crate) fn took(total: Duration, timer: &Timer) -> String {
+    format!("It took: {}ms ({timer})", as_ms(total))
+}
+
+pub(crate) fn as_ms(dur: Duration) -> f64 {
+    dur.as_nanos() as f64 / 1_000_000.0
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_timer_phases() {
+        let mut timer = Timer::default();
+        assert_eq!(timer.phase("parse", || 1), 1);
+        timer.phase("part1", || ());
+        timer.phase("parse", || ());
+
+        let names = timer.phases().map(|(name, _)| name).collect::<Vec<_>>();
+        assert_eq!(names, vec!["parse", "part1"]);
     }
-    Ok(res)
 }
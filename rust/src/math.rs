@@ -0,0 +1,292 @@
+//! Number theory for the puzzles that boil down to cycles lining up: gcd/lcm, extended
+//! Euclid, the Chinese remainder theorem and modular arithmetic.
+
+use std::fmt::Debug;
+use std::ops::{Add, Div, Mul, Rem, Sub};
+
+/// The primitive integer types the helpers work on.
+pub trait Integer:
+    Copy
+    + Debug
+    + Ord
+    + Add<Output = Self>
+    + Sub<Output = Self>
+    + Mul<Output = Self>
+    + Div<Output = Self>
+    + Rem<Output = Self>
+{
+    const ZERO: Self;
+    const ONE: Self;
+
+    /// The absolute value, the identity for unsigned types. `None` for `MIN` of a signed type,
+    /// whose absolute value doesn't fit.
+    fn checked_magnitude(self) -> Option<Self>;
+    fn checked_mul(self, rhs: Self) -> Option<Self>;
+}
+
+macro_rules! impl_integer {
+    ($($t:ty),* ; $($s:ty),*) => {
+        $(
+            impl Integer for $t {
+                const ZERO: Self = 0;
+                const ONE: Self = 1;
+
+                fn checked_magnitude(self) -> Option<Self> {
+                    Some(self)
+                }
+
+                fn checked_mul(self, rhs: Self) -> Option<Self> {
+                    <$t>::checked_mul(self, rhs)
+                }
+            }
+        )*
+        $(
+            impl Integer for $s {
+                const ZERO: Self = 0;
+                const ONE: Self = 1;
+
+                fn checked_magnitude(self) -> Option<Self> {
+                    self.checked_abs()
+                }
+
+                fn checked_mul(self, rhs: Self) -> Option<Self> {
+                    <$s>::checked_mul(self, rhs)
+                }
+            }
+        )*
+    };
+}
+
+impl_integer!(u32, u64, u128, usize; i32, i64, i128, isize);
+
+/// The greatest common divisor, always non-negative. `gcd(0, 0)` is 0.
+///
+/// Panics if the result doesn't fit in `T`, which only happens for `gcd(MIN, MIN)` and
+/// `gcd(MIN, 0)` of a signed type.
+pub fn gcd<T: Integer>(a: T, b: T) -> T {
+    let (mut a, mut b) = match (a.checked_magnitude(), b.checked_magnitude()) {
+        (Some(a), Some(b)) => (a, b),
+        // `MIN` has no magnitude, but its remainder by the other, non-zero, value has.
+        (None, Some(m)) if m != T::ZERO => (m, remainder_magnitude(a, m)),
+        (Some(m), None) if m != T::ZERO => (m, remainder_magnitude(b, m)),
+        _ => panic!("gcd of {a:?} and {b:?} doesn't fit"),
+    };
+    while b != T::ZERO {
+        (a, b) = (b, a % b);
+    }
+    a
+}
+
+/// `|a % m|` for a positive `m`, which always fits.
+fn remainder_magnitude<T: Integer>(a: T, m: T) -> T {
+    (a % m)
+        .checked_magnitude()
+        .expect("remainder smaller than the modulus")
+}
+
+/// The least common multiple, or `None` if it does not fit in `T`.
+pub fn lcm<T: Integer>(a: T, b: T) -> Option<T> {
+    if a == T::ZERO || b == T::ZERO {
+        return Some(T::ZERO);
+    }
+    // A multiple of `|MIN|` doesn't fit either.
+    let (a, b) = (a.checked_magnitude()?, b.checked_magnitude()?);
+    (a / gcd(a, b)).checked_mul(b)
+}
+
+/// The least common multiple of all values, 1 for none.
+pub fn lcm_all<T: Integer>(values: impl IntoIterator<Item = T>) -> Option<T> {
+    values
+        .into_iter()
+        .try_fold(T::ONE, |acc, value| lcm(acc, value))
+}
+
+/// Returns `(g, x, y)` with `a * x + b * y == g == gcd(a, b)`.
+pub fn ext_gcd(a: i128, b: i128) -> (i128, i128, i128) {
+    let (mut old_r, mut r) = (a, b);
+    let (mut old_x, mut x) = (1, 0);
+    let (mut old_y, mut y) = (0, 1);
+
+    while r != 0 {
+        let q = old_r / r;
+        (old_r, r) = (r, old_r - q * r);
+        (old_x, x) = (x, old_x - q * x);
+        (old_y, y) = (y, old_y - q * y);
+    }
+
+    if old_r < 0 {
+        (-old_r, -old_x, -old_y)
+    } else {
+        (old_r, old_x, old_y)
+    }
+}
+
+/// `a * b mod m` without overflowing, for `a, b < m`.
+fn mul_mod(a: u128, b: u128, m: u128) -> u128 {
+    if let Some(product) = a.checked_mul(b) {
+        return product % m;
+    }
+
+    let (mut a, mut b, mut result) = (a, b, 0);
+    while b > 0 {
+        if b & 1 == 1 {
+            result = add_mod(result, a, m);
+        }
+        a = add_mod(a, a, m);
+        b >>= 1;
+    }
+    result
+}
+
+fn add_mod(a: u128, b: u128, m: u128) -> u128 {
+    if a >= m - b {
+        a - (m - b)
+    } else {
+        a + b
+    }
+}
+
+/// `base^exp mod m`.
+///
+/// Panics if `m` is 0.
+pub fn mod_pow(base: u128, mut exp: u128, m: u128) -> u128 {
+    assert!(m > 0, "modulus must be positive");
+    if m == 1 {
+        return 0;
+    }
+
+    let mut base = base % m;
+    let mut result = 1;
+    while exp > 0 {
+        if exp & 1 == 1 {
+            result = mul_mod(result, base, m);
+        }
+        base = mul_mod(base, base, m);
+        exp >>= 1;
+    }
+    result
+}
+
+/// The `x` in `0..m` with `a * x ≡ 1 (mod m)`, if `m` is positive and `a` and `m` are
+/// coprime.
+pub fn mod_inv(a: i128, m: i128) -> Option<i128> {
+    if m <= 0 {
+        return None;
+    }
+    let (g, x, _) = ext_gcd(a.rem_euclid(m), m);
+    (g == 1).then(|| x.rem_euclid(m))
+}
+
+/// Solves the system `x ≡ r (mod m)` for all `(r, m)` pairs. The moduli need not be coprime.
+///
+/// Returns `(x, lcm)` with `x` the smallest non-negative solution, or `None` if a modulus isn't
+/// positive, the congruences contradict each other or the combined modulus overflows.
+pub fn crt(congruences: &[(i128, i128)]) -> Option<(i128, i128)> {
+    congruences.iter().try_fold((0, 1), |(r1, m1), &(r2, m2)| {
+        if m2 <= 0 {
+            return None;
+        }
+        let r2 = r2.rem_euclid(m2);
+        let g = gcd(m1, m2);
+        let diff = r2 - r1;
+        if diff % g != 0 {
+            return None;
+        }
+
+        let m2g = m2 / g;
+        let l = (m1 / g).checked_mul(m2)?;
+        // m1 * k ≡ diff (mod m2), reduced by g.
+        let inv = mod_inv(m1 / g, m2g)?;
+        let k = mul_mod((diff / g).rem_euclid(m2g) as u128, inv as u128, m2g as u128) as i128;
+
+        Some(((r1 + m1 * k).rem_euclid(l), l))
+    })
+}
+
+/// The largest `r` with `r * r <= n`.
+///
+/// Panics on negative `n`.
+pub fn isqrt<T: Integer>(n: T) -> T {
+    assert!(n >= T::ZERO, "square root of negative number {n:?}");
+    let two = T::ONE + T::ONE;
+    if n < two {
+        return n;
+    }
+
+    let mut x = n / two;
+    loop {
+        let y = (x + n / x) / two;
+        if y >= x {
+            return x;
+        }
+        x = y;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_gcd_lcm() {
+        assert_eq!(gcd(12_u64, 18), 6);
+        assert_eq!(gcd(-12_i128, 18), 6);
+        assert_eq!(gcd(0_u64, 0), 0);
+        assert_eq!(lcm(4_u64, 6), Some(12));
+        assert_eq!(lcm(u64::MAX, u64::MAX), Some(u64::MAX));
+        assert_eq!(lcm(u64::MAX, u64::MAX - 1), None);
+        assert_eq!(lcm_all([2_u128, 3, 4, 5]), Some(60));
+
+        assert_eq!(gcd(i32::MIN, 6), 2);
+        assert_eq!(gcd(1 << 20, i64::MIN), 1 << 20);
+        assert_eq!(gcd(i128::MIN, -1), 1);
+        assert_eq!(lcm(i32::MIN, 0), Some(0));
+        assert_eq!(lcm(i32::MIN, 2), None);
+        assert_eq!(lcm(-4_i64, 6), Some(12));
+
+        let (g, x, y) = ext_gcd(240, 46);
+        assert_eq!(g, 2);
+        assert_eq!(240 * x + 46 * y, 2);
+    }
+
+    #[test]
+    fn test_modular() {
+        assert_eq!(mod_pow(2, 10, 1000), 24);
+        assert_eq!(mod_pow(3, 0, 7), 1);
+        assert_eq!(mod_pow(u128::MAX - 1, 2, u128::MAX), 1);
+        assert_eq!(mod_inv(3, 11), Some(4));
+        assert_eq!(mod_inv(4, 8), None);
+
+        assert_eq!(crt(&[(2, 3), (3, 5), (2, 7)]), Some((23, 105)));
+        assert_eq!(crt(&[(3, 4), (1, 6)]), Some((7, 12)));
+        assert_eq!(crt(&[(1, 4), (2, 6)]), None);
+        assert_eq!(crt(&[]), Some((0, 1)));
+
+        assert_eq!(mod_inv(3, 0), None);
+        assert_eq!(mod_inv(3, -11), None);
+        assert_eq!(crt(&[(2, 3), (1, 0)]), None);
+        assert_eq!(crt(&[(1, -4)]), None);
+    }
+
+    #[test]
+    #[should_panic(expected = "doesn't fit")]
+    fn test_gcd_min() {
+        gcd(i64::MIN, 0);
+    }
+
+    #[test]
+    #[should_panic(expected = "modulus must be positive")]
+    fn test_mod_pow_zero() {
+        mod_pow(2, 3, 0);
+    }
+
+    #[test]
+    fn test_isqrt() {
+        for n in 0_u64..1000 {
+            let r = isqrt(n);
+            assert!(r * r <= n && (r + 1) * (r + 1) > n, "{n}");
+        }
+        assert_eq!(isqrt(u64::MAX), u32::MAX as u64);
+        assert_eq!(isqrt(u128::MAX), u64::MAX as u128);
+    }
+}
@@ -0,0 +1,210 @@
+//! Memoization for recursive and dynamic programming solutions.
+//!
+//! A `Memo` caches computed values either in a `HashMap` for sparse keys, or in a flat `Vec`
+//! for keys that are small indices. `solve` lets a recursive function reach the cache without
+//! threading it through every call by hand:
+//!
+//! ```
+//! use utils::memo::Memo;
+//!
+//! let fib = Memo::dense(91).solve(90, &|fib, n: usize| {
+//!     if n < 2 { n as u64 } else { fib(n - 1) + fib(n - 2) }
+//! });
+//! assert_eq!(fib, 2_880_067_194_370_816_120);
+//! ```
+
+use std::collections::HashMap;
+use std::hash::Hash;
+use std::marker::PhantomData;
+
+/// Where a `Memo` keeps its values.
+pub trait Store<K, V> {
+    fn get(&self, key: &K) -> Option<&V>;
+    fn insert(&mut self, key: K, value: V);
+}
+
+impl<K: Eq + Hash, V> Store<K, V> for HashMap<K, V> {
+    fn get(&self, key: &K) -> Option<&V> {
+        HashMap::get(self, key)
+    }
+
+    fn insert(&mut self, key: K, value: V) {
+        HashMap::insert(self, key, value);
+    }
+}
+
+/// Keys that map to a flat index, given the exclusive upper bound of each component.
+pub trait DenseKey {
+    fn size(shape: &Self) -> usize;
+    fn index(&self, shape: &Self) -> usize;
+}
+
+impl DenseKey for usize {
+    fn size(shape: &Self) -> usize {
+        *shape
+    }
+
+    fn index(&self, _: &Self) -> usize {
+        *self
+    }
+}
+
+impl DenseKey for (usize, usize) {
+    fn size(shape: &Self) -> usize {
+        shape.0 * shape.1
+    }
+
+    fn index(&self, shape: &Self) -> usize {
+        assert!(self.1 < shape.1, "Key {self:?} outside of {shape:?}");
+        self.0 * shape.1 + self.1
+    }
+}
+
+impl DenseKey for (usize, usize, usize) {
+    fn size(shape: &Self) -> usize {
+        shape.0 * shape.1 * shape.2
+    }
+
+    fn index(&self, shape: &Self) -> usize {
+        assert!(
+            self.1 < shape.1 && self.2 < shape.2,
+            "Key {self:?} outside of {shape:?}"
+        );
+        (self.0 * shape.1 + self.1) * shape.2 + self.2
+    }
+}
+
+/// An array-backed store for keys below a fixed shape.
+#[derive(Debug, Clone)]
+pub struct Dense<K, V> {
+    shape: K,
+    values: Vec<Option<V>>,
+}
+
+impl<K: DenseKey, V> Dense<K, V> {
+    pub fn new(shape: K) -> Self {
+        let mut values = Vec::new();
+        values.resize_with(K::size(&shape), || None);
+        Self { shape, values }
+    }
+}
+
+impl<K: DenseKey, V> Store<K, V> for Dense<K, V> {
+    fn get(&self, key: &K) -> Option<&V> {
+        self.values[key.index(&self.shape)].as_ref()
+    }
+
+    fn insert(&mut self, key: K, value: V) {
+        let i = key.index(&self.shape);
+        self.values[i] = Some(value);
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Memo<K, V, S = HashMap<K, V>> {
+    store: S,
+    marker: PhantomData<(K, V)>,
+}
+
+impl<K: Eq + Hash, V> Memo<K, V> {
+    /// A memo for sparse keys, backed by a `HashMap`.
+    pub fn new() -> Self {
+        Self::with_store(HashMap::new())
+    }
+}
+
+impl<K: Eq + Hash, V> Default for Memo<K, V> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<K: DenseKey, V> Memo<K, V, Dense<K, V>> {
+    /// A memo for keys below `shape`, backed by a `Vec`. Larger keys panic.
+    pub fn dense(shape: K) -> Self {
+        Self::with_store(Dense::new(shape))
+    }
+}
+
+impl<K, V, S> Memo<K, V, S> {
+    pub fn with_store(store: S) -> Self {
+        Self {
+            store,
+            marker: PhantomData,
+        }
+    }
+}
+
+impl<K: Clone, V: Clone, S: Store<K, V>> Memo<K, V, S> {
+    pub fn get(&self, key: &K) -> Option<&V> {
+        self.store.get(key)
+    }
+
+    pub fn insert(&mut self, key: K, value: V) {
+        self.store.insert(key, value);
+    }
+
+    /// The cached value for `key`, computing and caching it with `f` first if needed. `f` gets
+    /// the memo so it can look up or compute other keys.
+    pub fn get_or_compute(&mut self, key: K, f: impl FnOnce(&mut Self) -> V) -> V {
+        if let Some(value) = self.store.get(&key) {
+            return value.clone();
+        }
+
+        let value = f(self);
+        self.store.insert(key, value.clone());
+        value
+    }
+
+    /// Evaluates the recursive function `f` at `key`. `f` gets a function to recurse with
+    /// instead of calling itself, and every result is cached.
+    pub fn solve(&mut self, key: K, f: &impl Fn(&mut dyn FnMut(K) -> V, K) -> V) -> V {
+        if let Some(value) = self.store.get(&key) {
+            return value.clone();
+        }
+
+        let value = f(&mut |next| self.solve(next, f), key.clone());
+        self.store.insert(key, value.clone());
+        value
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn paths(memo: &mut Memo<(usize, usize), u64>, x: usize, y: usize) -> u64 {
+        memo.get_or_compute((x, y), |memo| match (x, y) {
+            (0, _) | (_, 0) => 1,
+            _ => paths(memo, x - 1, y) + paths(memo, x, y - 1),
+        })
+    }
+
+    #[test]
+    fn test_sparse() {
+        let mut memo = Memo::new();
+        assert_eq!(paths(&mut memo, 16, 16), 601_080_390);
+        assert_eq!(memo.get(&(2, 2)), Some(&6));
+        assert_eq!(memo.get(&(17, 0)), None);
+    }
+
+    #[test]
+    fn test_dense() {
+        let binomial = |n, k| {
+            Memo::dense((n + 1, k + 1)).solve((n, k), &|c, (n, k)| {
+                if k == 0 || k == n {
+                    1_u64
+                } else {
+                    c((n - 1, k - 1)) + c((n - 1, k.min(n - 1)))
+                }
+            })
+        };
+        assert_eq!(binomial(5, 2), 10);
+        assert_eq!(binomial(60, 30), 118_264_581_564_861_424);
+
+        let mut memo = Memo::<_, u8, _>::dense((2, 3, 4));
+        memo.insert((1, 2, 3), 7);
+        assert_eq!(memo.get(&(1, 2, 3)), Some(&7));
+        assert_eq!(memo.get(&(0, 2, 3)), None);
+    }
+}
@@ -0,0 +1,406 @@
+//! Small parser combinators for puzzle inputs.
+//!
+//! A parser takes the remaining input and returns the parsed value with what's left after it.
+//! Lists are strict: once a separator matched, the next item has to parse, so a malformed
+//! token is reported where it is instead of silently ending the list. `parse_all` runs a
+//! parser over a whole input and turns failures into a `ParseError` with line and column.
+//!
+//! ```
+//! use utils::parse::{int, parse_all, section, spaced, Parser};
+//!
+//! let seeds: Vec<u32> = parse_all("seeds: 79 14 55", section("seeds:", spaced(int()))).unwrap();
+//! assert_eq!(seeds, [79, 14, 55]);
+//!
+//! let err = parse_all("seeds: 79 1x4", section("seeds:", spaced(int::<u32>().named("seed"))));
+//! assert_eq!(err.unwrap_err().message, "invalid seed `1x4`");
+//! ```
+
+use std::str::FromStr;
+
+use crate::error::ParseError;
+
+/// Why and where a parser failed.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Failure<'a> {
+    /// The input remaining where the failure is.
+    pub at: &'a str,
+    /// The length of the offending token at `at`, 0 when something is missing.
+    pub len: usize,
+    pub what: String,
+    /// Whether a token was there but invalid, rather than something else being expected.
+    pub invalid: bool,
+}
+
+impl<'a> Failure<'a> {
+    pub fn expected(at: &'a str, what: impl Into<String>) -> Self {
+        Self {
+            at,
+            len: 0,
+            what: what.into(),
+            invalid: false,
+        }
+    }
+
+    pub fn invalid(at: &'a str, len: usize, what: impl Into<String>) -> Self {
+        Self {
+            at,
+            len,
+            what: what.into(),
+            invalid: true,
+        }
+    }
+
+    fn message(&self) -> String {
+        let token = &self.at[..self.len];
+        let found = self
+            .at
+            .lines()
+            .next()
+            .and_then(|line| line.split_whitespace().next());
+
+        match found {
+            _ if self.invalid => format!("invalid {} `{token}`", self.what),
+            Some(found) => format!("expected {}, found `{found}`", self.what),
+            None => format!("missing {}", self.what),
+        }
+    }
+
+    /// Converts to a `ParseError` on the line of `input` the failure is on. `self.at` must be
+    /// a suffix of `input`.
+    pub fn into_error(self, input: &str) -> ParseError {
+        let offset = input.len() - self.at.len();
+        let line_start = input[..offset].rfind('\n').map_or(0, |i| i + 1);
+        let line_end = input[offset..]
+            .find('\n')
+            .map_or(input.len(), |i| offset + i);
+        let line_nr = input[..offset].matches('\n').count() + 1;
+
+        let line = &input[line_start..line_end];
+        let span_end = (offset + self.len).min(line_end);
+        let span = &line[offset - line_start..span_end - line_start];
+
+        ParseError::new(line, span, self.message()).on_line(line_nr)
+    }
+}
+
+pub type PResult<'a, T> = Result<(T, &'a str), Failure<'a>>;
+
+pub trait Parser<'a, T> {
+    fn parse(&self, input: &'a str) -> PResult<'a, T>;
+
+    fn map<U>(self, f: impl Fn(T) -> U) -> impl Parser<'a, U>
+    where
+        Self: Sized,
+    {
+        move |input: &'a str| {
+            let (value, rest) = self.parse(input)?;
+            Ok((f(value), rest))
+        }
+    }
+
+    /// Names what's expected, for failures right at the start of this parser.
+    fn named(self, what: &'static str) -> impl Parser<'a, T>
+    where
+        Self: Sized,
+    {
+        move |input: &'a str| {
+            self.parse(input).map_err(|mut failure| {
+                if failure.at.len() == input.len() {
+                    failure.what = what.to_string();
+                }
+                failure
+            })
+        }
+    }
+}
+
+impl<'a, T, F> Parser<'a, T> for F
+where
+    F: Fn(&'a str) -> PResult<'a, T>,
+{
+    fn parse(&self, input: &'a str) -> PResult<'a, T> {
+        self(input)
+    }
+}
+
+/// Runs `parser` over all of `input`, allowing only trailing whitespace after it.
+pub fn parse_all<'a, T>(input: &'a str, parser: impl Parser<'a, T>) -> Result<T, ParseError> {
+    let (value, rest) = parser.parse(input).map_err(|f| f.into_error(input))?;
+    if !rest.trim().is_empty() {
+        let at = rest.trim_start();
+        let len = at.find(char::is_whitespace).unwrap_or(at.len());
+        return Err(Failure::invalid(at, len, "trailing input").into_error(input));
+    }
+    Ok(value)
+}
+
+/// The literal `expected`.
+pub fn tag<'a>(expected: &'static str) -> impl Parser<'a, &'a str> {
+    move |input: &'a str| match input.strip_prefix(expected) {
+        Some(rest) => Ok((&input[..expected.len()], rest)),
+        None => Err(Failure::expected(input, format!("`{expected}`"))),
+    }
+}
+
+/// One or more characters matching `pred`.
+pub fn take_while1<'a>(
+    pred: impl Fn(char) -> bool,
+    what: &'static str,
+) -> impl Parser<'a, &'a str> {
+    move |input: &'a str| {
+        let len = input.find(|c| !pred(c)).unwrap_or(input.len());
+        if len == 0 {
+            Err(Failure::expected(input, what))
+        } else {
+            Ok(input.split_at(len))
+        }
+    }
+}
+
+/// A run of letters and digits.
+pub fn word<'a>() -> impl Parser<'a, &'a str> {
+    take_while1(char::is_alphanumeric, "word")
+}
+
+/// Zero or more spaces or tabs, but not newlines.
+pub fn spaces<'a>() -> impl Parser<'a, ()> {
+    |input: &'a str| Ok(((), input.trim_start_matches([' ', '\t'])))
+}
+
+/// A line break, `\n` or `\r\n`.
+pub fn newline<'a>() -> impl Parser<'a, ()> {
+    |input: &'a str| match input
+        .strip_prefix('\n')
+        .or_else(|| input.strip_prefix("\r\n"))
+    {
+        Some(rest) => Ok(((), rest)),
+        None => Err(Failure::expected(input, "end of line")),
+    }
+}
+
+/// An integer with an optional sign. The whole token up to the next separator has to be a
+/// valid number, so `5O` is an invalid integer rather than `5` followed by `O`.
+pub fn int<'a, T: FromStr>() -> impl Parser<'a, T> {
+    |input: &'a str| {
+        let sign = usize::from(input.starts_with(['-', '+']));
+        let len = sign
+            + input[sign..]
+                .find(|c: char| !(c.is_alphanumeric() || c == '_'))
+                .unwrap_or(input.len() - sign);
+
+        match input[..len].parse() {
+            Ok(value) => Ok((value, &input[len..])),
+            Err(_) if len == 0 => Err(Failure::expected(input, "integer")),
+            Err(_) => Err(Failure::invalid(input, len, "integer")),
+        }
+    }
+}
+
+pub fn pair<'a, A, B>(a: impl Parser<'a, A>, b: impl Parser<'a, B>) -> impl Parser<'a, (A, B)> {
+    move |input: &'a str| {
+        let (first, rest) = a.parse(input)?;
+        let (second, rest) = b.parse(rest)?;
+        Ok(((first, second), rest))
+    }
+}
+
+/// `parser` after `prefix`, whose value is dropped.
+pub fn preceded<'a, P, T>(
+    prefix: impl Parser<'a, P>,
+    parser: impl Parser<'a, T>,
+) -> impl Parser<'a, T> {
+    pair(prefix, parser).map(|(_, value)| value)
+}
+
+/// `parser` before `suffix`, whose value is dropped.
+pub fn terminated<'a, T, S>(
+    parser: impl Parser<'a, T>,
+    suffix: impl Parser<'a, S>,
+) -> impl Parser<'a, T> {
+    pair(parser, suffix).map(|(value, _)| value)
+}
+
+pub fn delimited<'a, O, T, C>(
+    open: impl Parser<'a, O>,
+    parser: impl Parser<'a, T>,
+    close: impl Parser<'a, C>,
+) -> impl Parser<'a, T> {
+    preceded(open, terminated(parser, close))
+}
+
+/// Zero or more `item`s between `sep`s. After a separator an item is required.
+pub fn sep_by<'a, T, S>(
+    item: impl Parser<'a, T>,
+    sep: impl Parser<'a, S>,
+) -> impl Parser<'a, Vec<T>> {
+    move |input: &'a str| match item.parse(input) {
+        Ok((first, rest)) => rest_of_list(first, rest, &item, &sep),
+        Err(_) => Ok((vec![], input)),
+    }
+}
+
+/// One or more `item`s between `sep`s.
+pub fn sep_by1<'a, T, S>(
+    item: impl Parser<'a, T>,
+    sep: impl Parser<'a, S>,
+) -> impl Parser<'a, Vec<T>> {
+    move |input: &'a str| {
+        let (first, rest) = item.parse(input)?;
+        rest_of_list(first, rest, &item, &sep)
+    }
+}
+
+fn rest_of_list<'a, T, S>(
+    first: T,
+    mut rest: &'a str,
+    item: &impl Parser<'a, T>,
+    sep: &impl Parser<'a, S>,
+) -> PResult<'a, Vec<T>> {
+    let mut items = vec![first];
+    while let Ok((_, after_sep)) = sep.parse(rest) {
+        let (next, after_item) = item.parse(after_sep)?;
+        items.push(next);
+        rest = after_item;
+    }
+    Ok((items, rest))
+}
+
+/// `separator` only where something else follows on the same line.
+fn between<'a, S>(separator: impl Parser<'a, S>) -> impl Parser<'a, ()> {
+    move |input: &'a str| {
+        let (_, rest) = separator.parse(input)?;
+        if rest.is_empty() || rest.starts_with(['\n', '\r']) {
+            Err(Failure::expected(input, "more input"))
+        } else {
+            Ok(((), rest))
+        }
+    }
+}
+
+/// One or more `item`s separated by spaces on one line.
+pub fn spaced<'a, T>(item: impl Parser<'a, T>) -> impl Parser<'a, Vec<T>> {
+    sep_by1(
+        item,
+        between(take_while1(|c| c == ' ' || c == '\t', "space")),
+    )
+}
+
+/// One or more `item`s separated by `sep` and optional spaces around it, like `1, 2, 3`.
+pub fn list<'a, T>(item: impl Parser<'a, T>, sep: &'static str) -> impl Parser<'a, Vec<T>> {
+    sep_by1(item, delimited(spaces(), tag(sep), spaces()))
+}
+
+/// One `item` per line, stopping at a blank line or the end of the input.
+pub fn lines<'a, T>(item: impl Parser<'a, T>) -> impl Parser<'a, Vec<T>> {
+    sep_by1(item, between(newline()))
+}
+
+/// One or more lines of nothing but whitespace, between two non-blank lines.
+pub fn blank_line<'a>() -> impl Parser<'a, ()> {
+    |input: &'a str| {
+        let (_, mut rest) = newline().parse(input)?;
+        let mut blank = false;
+        while let Some(line_end) = rest.find('\n') {
+            if !rest[..line_end].trim().is_empty() {
+                break;
+            }
+            rest = &rest[line_end + 1..];
+            blank = true;
+        }
+        if blank && !rest.trim().is_empty() {
+            Ok(((), rest))
+        } else {
+            Err(Failure::expected(input, "blank line"))
+        }
+    }
+}
+
+/// One or more `block`s separated by blank lines.
+pub fn blocks<'a, T>(block: impl Parser<'a, T>) -> impl Parser<'a, Vec<T>> {
+    sep_by1(block, blank_line())
+}
+
+/// `parser` after a `label`, like `Time:` or `seeds:`, and optional spaces.
+pub fn section<'a, T>(label: &'static str, parser: impl Parser<'a, T>) -> impl Parser<'a, T> {
+    preceded(pair(tag(label), spaces()), parser)
+}
+
+/// A `key`, then `sep` with optional spaces around it, then a `value`.
+pub fn key_value<'a, K, V>(
+    key: impl Parser<'a, K>,
+    sep: &'static str,
+    value: impl Parser<'a, V>,
+) -> impl Parser<'a, (K, V)> {
+    pair(
+        terminated(key, delimited(spaces(), tag(sep), spaces())),
+        value,
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_primitives() {
+        assert_eq!(int::<i32>().parse("-12 3"), Ok((-12, " 3")));
+        assert_eq!(
+            int::<u8>().parse("300"),
+            Err(Failure::invalid("300", 3, "integer"))
+        );
+        assert_eq!(word().parse("AAA = "), Ok(("AAA", " = ")));
+        assert_eq!(
+            spaced(int::<i32>()).parse("1  2 3 \nx"),
+            Ok((vec![1, 2, 3], " \nx"))
+        );
+        assert_eq!(
+            list(int::<i32>(), ",").parse("1, 2,3"),
+            Ok((vec![1, 2, 3], ""))
+        );
+        assert_eq!(
+            key_value(
+                word(),
+                "=",
+                delimited(tag("("), list(word(), ","), tag(")"))
+            )
+            .parse("AAA = (BBB, CCC)"),
+            Ok((("AAA", vec!["BBB", "CCC"]), ""))
+        );
+    }
+
+    #[test]
+    fn test_blocks() {
+        let input = "a:\n1\n2\n\n  \nb:\n3\n";
+        let block = pair(
+            terminated(word(), pair(tag(":"), newline())),
+            lines(int::<i32>()),
+        );
+        assert_eq!(
+            parse_all(input, blocks(block)),
+            Ok(vec![("a", vec![1, 2]), ("b", vec![3])])
+        );
+    }
+
+    #[test]
+    fn test_errors() {
+        let numbers = || section("Time:", spaced(int::<i32>().named("time")));
+
+        let err = parse_all("Time: 1 2\nTime: 3 x", lines(numbers())).unwrap_err();
+        assert_eq!(err.message, "invalid time `x`");
+        assert_eq!((err.line, err.column, err.len), (2, 9, 1));
+
+        let err = parse_all("Time:", numbers()).unwrap_err();
+        assert_eq!(err.message, "missing time");
+        assert_eq!((err.line, err.column), (1, 6));
+
+        let err = parse_all("Time: 3; 4", terminated(numbers(), tag(","))).unwrap_err();
+        assert_eq!(err.message, "expected `,`, found `;`");
+
+        let err = parse_all("Dist: 3", numbers()).unwrap_err();
+        assert_eq!(err.message, "expected `Time:`, found `Dist:`");
+
+        let err = parse_all("Time: 3\nfoo", numbers()).unwrap_err();
+        assert_eq!(err.message, "invalid trailing input `foo`");
+        assert_eq!((err.line, err.column, err.len), (2, 1, 3));
+    }
+}
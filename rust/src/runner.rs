@@ -0,0 +1,252 @@
+//! Running many days in one go, either concurrently on a thread pool or one after another for
+//! clean timings, and summarising their answers, status and times as a table.
+
+use std::fmt;
+use std::time::{Duration, Instant};
+
+use anyhow::Result;
+use rayon::prelude::*;
+
+use crate::answers::{Registry, Status};
+use crate::input::Source;
+use crate::{as_ms, input_hash, Answers, Day, Parts};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Mode {
+    /// All days at once on a thread pool, with as many threads as CPUs unless a limit is given.
+    Parallel { threads: Option<usize> },
+    /// One day at a time, so the times aren't skewed by the other days competing for the CPU.
+    Sequential,
+}
+
+/// The outcome of one day in a summary, where an error is kept as its message.
+#[derive(Debug, Clone)]
+pub struct Row {
+    pub day: u8,
+    pub source: String,
+    pub result: Result<Solved, String>,
+}
+
+#[derive(Debug, Clone)]
+pub struct Solved {
+    pub answers: Answers,
+    /// The status of each solved part against the known answers.
+    pub statuses: Vec<Status>,
+    pub total: Duration,
+}
+
+impl Row {
+    /// `ERROR` if the day didn't run, otherwise the worst status of its parts.
+    pub fn status(&self) -> &'static str {
+        let Ok(solved) = &self.result else {
+            return "ERROR";
+        };
+        let any = |f: fn(&Status) -> bool| solved.statuses.iter().any(f);
+        if any(|s| matches!(s, Status::Fail { .. })) {
+            "FAIL"
+        } else if any(|s| *s == Status::Missing) {
+            "MISSING"
+        } else {
+            "PASS"
+        }
+    }
+
+    fn failed(&self) -> bool {
+        matches!(self.status(), "ERROR" | "FAIL")
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Summary {
+    pub rows: Vec<Row>,
+    /// The time from starting the first day until the last one finished.
+    pub wall: Duration,
+    pub threads: usize,
+}
+
+impl Summary {
+    /// The days that errored or gave an answer other than the known one.
+    pub fn failed(&self) -> usize {
+        self.rows.iter().filter(|row| row.failed()).count()
+    }
+}
+
+/// Runs all `jobs` as `mode` says, checking the answers against `registry`.
+pub fn run_all(jobs: &[(&Day, Source)], mode: Mode, registry: &Registry) -> Result<Summary> {
+    let start = Instant::now();
+    let (rows, threads) = match mode {
+        Mode::Sequential => (jobs.iter().map(|job| run(job, registry)).collect(), 1),
+        Mode::Parallel { threads } => {
+            let pool = rayon::ThreadPoolBuilder::new()
+                .num_threads(threads.unwrap_or_else(num_cpus::get))
+                .build()?;
+            let rows = pool.install(|| jobs.par_iter().map(|job| run(job, registry)).collect());
+            (rows, pool.current_num_threads())
+        }
+    };
+
+    Ok(Summary {
+        rows,
+        wall: start.elapsed(),
+        threads,
+    })
+}
+
+fn run((day, source): &(&Day, Source), registry: &Registry) -> Row {
+    let result = day.run_timed(source, Parts::Both).and_then(|run| {
+        let input_hash = input_hash(source.read()?.as_bytes());
+        let statuses = run
+            .answers
+            .parts()
+            .map(|(part, answer)| registry.check(day.day, part, &input_hash, answer))
+            .collect();
+        Ok(Solved {
+            answers: run.answers,
+            statuses,
+            total: run.total,
+        })
+    });
+
+    Row {
+        day: day.day,
+        source: source.to_string(),
+        result: result.map_err(|e| format!("{e:#}")),
+    }
+}
+
+impl fmt::Display for Summary {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let header = ["Day", "Input", "Part 1", "Part 2", "Status", "Time"].map(String::from);
+        let cells = self
+            .rows
+            .iter()
+            .map(|row| {
+                let (part1, part2, time) = match &row.result {
+                    Ok(solved) => (
+                        solved.answers.part1.clone().unwrap_or_default(),
+                        solved.answers.part2.clone().unwrap_or_default(),
+                        format!("{:.4}ms", as_ms(solved.total)),
+                    ),
+                    Err(_) => ("-".to_string(), "-".to_string(), "-".to_string()),
+                };
+                let day = format!("{:02}", row.day);
+                [
+                    day,
+                    row.source.clone(),
+                    part1,
+                    part2,
+                    row.status().into(),
+                    time,
+                ]
+            })
+            .collect::<Vec<_>>();
+
+        let mut widths = header.clone().map(|h| h.len());
+        for row in &cells {
+            for (width, cell) in widths.iter_mut().zip(row) {
+                *width = (*width).max(cell.chars().count());
+            }
+        }
+
+        for row in std::iter::once(&header).chain(&cells) {
+            let line = row
+                .iter()
+                .zip(widths)
+                .enumerate()
+                .map(|(i, (cell, width))| match i {
+                    5 => format!("{cell:>width$}"),
+                    _ => format!("{cell:<width$}"),
+                })
+                .collect::<Vec<_>>();
+            writeln!(f, "{}", line.join("  ").trim_end())?;
+        }
+
+        for row in &self.rows {
+            if let Err(e) = &row.result {
+                writeln!(f, "\nDay {:02} ({}): {e}", row.day, row.source)?;
+            }
+        }
+
+        let count = |status| self.rows.iter().filter(|r| r.status() == status).count();
+        let summed = self
+            .rows
+            .iter()
+            .filter_map(|r| r.result.as_ref().ok())
+            .map(|solved| solved.total)
+            .sum();
+        write!(
+            f,
+            "\n{} day(s): {} passed, {} failed, {} missing, {} errored\n\
+             Took {:.4}ms on {} thread(s), {:.4}ms summed over the days",
+            self.rows.len(),
+            count("PASS"),
+            count("FAIL"),
+            count("MISSING"),
+            count("ERROR"),
+            as_ms(self.wall),
+            self.threads,
+            as_ms(summed)
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn row(day: u8, part1: &str, statuses: Vec<Status>, ms: u64) -> Row {
+        Row {
+            day,
+            source: format!("../input/day{day:02}"),
+            result: Ok(Solved {
+                answers: Answers {
+                    part1: Some(part1.to_string()),
+                    part2: Some("46".to_string()),
+                },
+                statuses,
+                total: Duration::from_millis(ms),
+            }),
+        }
+    }
+
+    #[test]
+    fn test_summary() {
+        let fail = Status::Fail {
+            expected: "35".to_string(),
+        };
+        let summary = Summary {
+            rows: vec![
+                row(5, "35", vec![Status::Pass, Status::Pass], 2),
+                row(6, "288", vec![Status::Missing, Status::Pass], 10),
+                row(7, "6440", vec![Status::Missing, fail], 1),
+                Row {
+                    day: 8,
+                    source: "stdin".to_string(),
+                    result: Err("Unable to read stdin".to_string()),
+                },
+            ],
+            wall: Duration::from_millis(12),
+            threads: 4,
+        };
+
+        assert_eq!(
+            summary.rows.iter().map(Row::status).collect::<Vec<_>>(),
+            ["PASS", "MISSING", "FAIL", "ERROR"]
+        );
+        assert_eq!(summary.failed(), 2);
+        assert_eq!(
+            summary.to_string(),
+            "\
+Day  Input           Part 1  Part 2  Status        Time
+05   ../input/day05  35      46      PASS      2.0000ms
+06   ../input/day06  288     46      MISSING  10.0000ms
+07   ../input/day07  6440    46      FAIL      1.0000ms
+08   stdin           -       -       ERROR            -
+
+Day 08 (stdin): Unable to read stdin
+
+4 day(s): 1 passed, 1 failed, 1 missing, 1 errored
+Took 12.0000ms on 4 thread(s), 13.0000ms summed over the days"
+        );
+    }
+}
@@ -0,0 +1,275 @@
+//! Generates the skeleton of a new day: its module, registered in `days/mod.rs`, its binary, and
+//! empty files for the puzzle input and the example from the puzzle text.
+
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+
+use anyhow::{bail, Context, Result};
+
+/// The shape of the puzzle input, which decides the skeleton's `Input` type and `parse`.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum Shape {
+    /// One `String` per line.
+    #[default]
+    Lines,
+    /// A `Grid<char>`.
+    Grid,
+    /// Blocks separated by blank lines, each a `Vec` of its lines.
+    Blocks,
+}
+
+impl FromStr for Shape {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        Ok(match s {
+            "lines" => Shape::Lines,
+            "grid" => Shape::Grid,
+            "blocks" => Shape::Blocks,
+            _ => bail!("Invalid input shape {s}, expected lines, grid or blocks"),
+        })
+    }
+}
+
+const MODULE: &str = r#"//! Day {day}{title}
+
+use anyhow::Result;
+{imports}
+use crate::Solution;
+
+pub type Input = {input};
+
+pub fn part1(_input: &Input) -> usize {
+    0
+}
+
+pub fn part2(_input: &Input) -> usize {
+    0
+}
+
+pub struct Day{nn};
+
+impl Solution for Day{nn} {
+    const DAY: u8 = {day};
+    type Input = Input;
+    type Output1 = usize;
+    type Output2 = usize;
+
+    fn parse(input: &str) -> Result<Input> {
+        parse(input)
+    }
+
+    fn part1(input: &Input) -> usize {
+        part1(input)
+    }
+
+    fn part2(input: &Input) -> usize {
+        part2(input)
+    }
+}
+
+/// Parses the puzzle input.
+pub fn parse(input: &str) -> Result<Input> {
+{parse}
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const INPUT: &str = include_str!("../../../input/examples/day{nn}");
+
+    #[test]
+    #[ignore = "needs the example and its answer"]
+    fn test_part1() -> Result<()> {
+        assert_eq!(part1(&parse(INPUT)?), 0);
+        Ok(())
+    }
+
+    #[test]
+    #[ignore = "needs the example and its answer"]
+    fn test_part2() -> Result<()> {
+        assert_eq!(part2(&parse(INPUT)?), 0);
+        Ok(())
+    }
+}
+"#;
+
+const BIN: &str = r#"//! Day {day} on its own, the same as `aoc {day}`.
+
+fn main() -> anyhow::Result<()> {
+    utils::days::run_bin({day})
+}
+"#;
+
+/// The source of the module for `day`, with `title` in its header if it isn't empty.
+pub fn module(day: u8, title: &str, shape: Shape) -> String {
+    let (imports, input, parse) = match shape {
+        Shape::Lines => (
+            "",
+            "Vec<String>",
+            "    Ok(input.lines().map(str::to_string).collect())",
+        ),
+        Shape::Grid => (
+            "\nuse crate::grid::Grid;",
+            "Grid<char>",
+            "    Ok(input.trim_end().parse()?)",
+        ),
+        Shape::Blocks => (
+            "",
+            "Vec<Vec<String>>",
+            "    Ok(input
+        .split(\"\\n\\n\")
+        .map(|block| block.lines().map(str::to_string).collect())
+        .collect())",
+        ),
+    };
+    let title = match title {
+        "" => String::new(),
+        title => format!(": {title}"),
+    };
+
+    MODULE
+        .replace("{day}", &day.to_string())
+        .replace("{nn}", &format!("{day:02}"))
+        .replace("{imports}", imports)
+        .replace("{input}", input)
+        .replace("{parse}", parse)
+        // Last, so placeholders in the title are left as they are.
+        .replace("{title}", &title)
+}
+
+/// The `dayNN` binary, which only hands over to the library.
+pub fn bin(day: u8) -> String {
+    BIN.replace("{day}", &day.to_string())
+}
+
+/// `days/mod.rs` with the module of `day` declared and the day added to `DAYS`, both kept in
+/// order.
+pub fn register(mod_rs: &str, day: u8) -> Result<String> {
+    let module = format!("pub mod day{day:02};");
+    let entry = format!("    Day::new::<day{day:02}::Day{day:02}>(),");
+    if mod_rs.lines().any(|line| line == module) {
+        bail!("Day {day} is already registered");
+    }
+
+    let mut lines = mod_rs.lines().collect::<Vec<_>>();
+    insert_sorted(&mut lines, &module, |line| line.starts_with("pub mod day"))?;
+    insert_sorted(&mut lines, &entry, |line| {
+        line.starts_with("    Day::new::<day")
+    })?;
+
+    Ok(lines.join("\n") + "\n")
+}
+
+/// Inserts `new` among the consecutive lines matching `is_item`, before the first that sorts
+/// after it.
+fn insert_sorted<'a>(
+    lines: &mut Vec<&'a str>,
+    new: &'a str,
+    is_item: impl Fn(&str) -> bool,
+) -> Result<()> {
+    let first = lines
+        .iter()
+        .position(|line| is_item(line))
+        .context("No days found in days/mod.rs")?;
+    let end = first
+        + lines[first..]
+            .iter()
+            .take_while(|line| is_item(line))
+            .count();
+    let at = (first..end).find(|&i| lines[i] > new).unwrap_or(end);
+    lines.insert(at, new);
+    Ok(())
+}
+
+/// Creates the module of `day` in the crate at `root` and registers it, and creates empty
+/// input and example files in `../input` if they don't exist yet. Returns the files written.
+pub fn create(root: &Path, day: u8, title: &str, shape: Shape) -> Result<Vec<PathBuf>> {
+    if !(1..=25).contains(&day) {
+        bail!("Invalid day {day}, expected 1 to 25");
+    }
+    let days = root.join("src/days");
+    let module_path = days.join(format!("day{day:02}.rs"));
+    if module_path.exists() {
+        bail!("{} already exists", module_path.display());
+    }
+
+    let mod_rs_path = days.join("mod.rs");
+    let mod_rs = fs::read_to_string(&mod_rs_path)
+        .with_context(|| format!("Unable to read {}", mod_rs_path.display()))?;
+    let mod_rs = register(&mod_rs, day)?;
+
+    let input = root.join("../input");
+    let mut written = vec![];
+    for (path, content) in [
+        (module_path, module(day, title, shape)),
+        (mod_rs_path, mod_rs),
+        (root.join(format!("src/bin/day{day:02}.rs")), bin(day)),
+        (input.join(format!("day{day:02}")), String::new()),
+        (input.join(format!("examples/day{day:02}")), String::new()),
+    ] {
+        if content.is_empty() && path.exists() {
+            continue;
+        }
+        if let Some(dir) = path.parent() {
+            fs::create_dir_all(dir)
+                .with_context(|| format!("Unable to create {}", dir.display()))?;
+        }
+        fs::write(&path, content).with_context(|| format!("Unable to write {}", path.display()))?;
+        written.push(path);
+    }
+    Ok(written)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const MOD_RS: &str = "pub mod day01;
+pub mod day03;
+
+pub const DAYS: &[Day] = &[
+    Day::new::<day01::Day01>(),
+    Day::new::<day03::Day03>(),
+];
+";
+
+    #[test]
+    fn test_register() -> Result<()> {
+        assert_eq!(
+            register(MOD_RS, 2)?,
+            "pub mod day01;
+pub mod day02;
+pub mod day03;
+
+pub const DAYS: &[Day] = &[
+    Day::new::<day01::Day01>(),
+    Day::new::<day02::Day02>(),
+    Day::new::<day03::Day03>(),
+];
+"
+        );
+        assert!(register(&register(MOD_RS, 4)?, 4).is_err());
+        assert!(register(MOD_RS, 3).is_err());
+        assert!(register("", 3).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_module() {
+        let grid = module(7, "Camel Cards", Shape::Grid);
+        assert!(grid.starts_with("//! Day 7: Camel Cards\n"));
+        assert!(grid.contains("pub struct Day07;"));
+        assert!(grid.contains("pub type Input = Grid<char>;"));
+        assert!(grid.contains("include_str!(\"../../../input/examples/day07\")"));
+        assert!(module(7, "", Shape::Lines).starts_with("//! Day 7\n\nuse anyhow::Result;\n\n"));
+        assert!("dots".parse::<Shape>().is_err());
+        assert_eq!(bin(7), include_str!("bin/day07.rs"));
+
+        let braces = module(7, "{day} {parse} {input}", Shape::Lines);
+        assert!(braces.starts_with("//! Day 7: {day} {parse} {input}\n"));
+        assert!(braces.contains("pub type Input = Vec<String>;"));
+    }
+}
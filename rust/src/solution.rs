@@ -0,0 +1,239 @@
+use std::fmt::{Display, Write};
+use std::str::FromStr;
+use std::time::{Duration, Instant};
+
+use anyhow::{bail, Result};
+
+use crate::error::ParseError;
+use crate::input::Source;
+use crate::viz::Visualizer;
+use crate::{as_ms, json, Timer};
+
+/// A solver for one day of the puzzle calendar.
+pub trait Solution {
+    const DAY: u8;
+
+    type Input;
+    type Output1: Display;
+    type Output2: Display;
+
+    fn parse(input: &str) -> Result<Self::Input>;
+
+    fn part1(input: &Self::Input) -> Self::Output1;
+
+    fn part2(input: &Self::Input) -> Self::Output2;
+
+    /// Shows the puzzle state as frames. Days without a visualization show nothing.
+    fn visualize(_input: &Self::Input, _viz: &mut Visualizer) -> Result<()> {
+        Ok(())
+    }
+}
+
+/// Which parts of a day to solve.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum Parts {
+    One,
+    Two,
+    #[default]
+    Both,
+}
+
+impl Parts {
+    pub fn includes(self, part: u8) -> bool {
+        matches!(
+            (self, part),
+            (Parts::Both, _) | (Parts::One, 1) | (Parts::Two, 2)
+        )
+    }
+}
+
+impl FromStr for Parts {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        Ok(match s {
+            "1" => Parts::One,
+            "2" => Parts::Two,
+            "both" => Parts::Both,
+            _ => bail!("Invalid part {s}, expected 1, 2 or both"),
+        })
+    }
+}
+
+/// The answers of the parts that were solved.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct Answers {
+    pub part1: Option<String>,
+    pub part2: Option<String>,
+}
+
+impl Answers {
+    /// The solved parts with their answers, in order.
+    pub fn parts(&self) -> impl Iterator<Item = (u8, &str)> {
+        [(1, &self.part1), (2, &self.part2)]
+            .into_iter()
+            .filter_map(|(part, answer)| Some((part, answer.as_deref()?)))
+    }
+}
+
+/// Solves the selected parts, timing the parse and each part as separate phases.
+pub fn solve<S: Solution>(input: &str, parts: Parts, timer: &mut Timer) -> Result<Answers> {
+    let input = timer.phase("parse", || S::parse(input))?;
+    let part1 = parts
+        .includes(1)
+        .then(|| timer.phase("part1", || S::part1(&input).to_string()));
+    let part2 = parts
+        .includes(2)
+        .then(|| timer.phase("part2", || S::part2(&input).to_string()));
+    Ok(Answers { part1, part2 })
+}
+
+fn visualize<S: Solution>(input: &str, viz: &mut Visualizer) -> Result<()> {
+    S::visualize(&S::parse(input)?, viz)
+}
+
+/// Identifies an input by its contents, so results for different inputs are never mixed up.
+pub fn input_hash(bytes: &[u8]) -> String {
+    format!("{:x}", md5::compute(bytes))
+}
+
+/// A registered day, with the concrete `Solution` erased so days can be kept in one list.
+#[derive(Clone, Copy)]
+pub struct Day {
+    pub day: u8,
+    solve: fn(&str, Parts, &mut Timer) -> Result<Answers>,
+    visualize: fn(&str, &mut Visualizer) -> Result<()>,
+}
+
+impl Day {
+    pub const fn new<S: Solution>() -> Self {
+        Self {
+            day: S::DAY,
+            solve: solve::<S>,
+            visualize: visualize::<S>,
+        }
+    }
+
+    pub fn default_input(&self) -> Source {
+        Source::default_for(self.day)
+    }
+
+    pub fn run(&self, source: &Source, timer: &mut Timer) -> Result<Answers> {
+        self.run_parts(source, Parts::Both, timer)
+    }
+
+    pub fn run_parts(&self, source: &Source, parts: Parts, timer: &mut Timer) -> Result<Answers> {
+        let input = timer.phase("read", || source.read())?;
+        (self.solve)(&input, parts, timer).map_err(|e| in_file(e, source))
+    }
+
+    pub fn visualize(&self, source: &Source, viz: &mut Visualizer) -> Result<()> {
+        (self.visualize)(&source.read()?, viz).map_err(|e| in_file(e, source))
+    }
+
+    /// Runs once, keeping the total time and the time of each phase.
+    pub fn run_timed(&self, source: &Source, parts: Parts) -> Result<Run> {
+        let mut timer = Timer::default();
+        let start = Instant::now();
+        let answers = self.run_parts(source, parts, &mut timer)?;
+
+        Ok(Run {
+            day: self.day,
+            source: source.to_string(),
+            answers,
+            total: start.elapsed(),
+            timer,
+        })
+    }
+}
+
+fn in_file(e: anyhow::Error, source: &Source) -> anyhow::Error {
+    match e.downcast::<ParseError>() {
+        Ok(e) => e.in_file(source.to_string()).into(),
+        Err(e) => e,
+    }
+}
+
+/// The outcome of a single timed run of a day.
+#[derive(Debug, Clone)]
+pub struct Run {
+    pub day: u8,
+    pub source: String,
+    pub answers: Answers,
+    pub total: Duration,
+    pub timer: Timer,
+}
+
+impl Run {
+    /// The `It took: ...` line with the total and per phase times.
+    pub fn timing(&self) -> String {
+        crate::took(self.total, &self.timer)
+    }
+
+    /// A single line JSON object with the answers, and the times in ms if `timings` is set.
+    pub fn to_json(&self, timings: bool) -> String {
+        let mut out = format!(
+            "{{\"day\": {}, \"input\": {}",
+            self.day,
+            json::string(&self.source)
+        );
+        for (name, answer) in [
+            ("part1", &self.answers.part1),
+            ("part2", &self.answers.part2),
+        ] {
+            let answer = answer.as_deref().map_or("null".to_string(), json::string);
+            let _ = write!(out, ", \"{name}\": {answer}");
+        }
+        if timings {
+            let phases = self
+                .timer
+                .phases()
+                .map(|(name, dur)| format!("{}: {}", json::string(name), as_ms(dur)))
+                .collect::<Vec<_>>();
+            let _ = write!(
+                out,
+                ", \"time_ms\": {}, \"phases\": {{{}}}",
+                as_ms(self.total),
+                phases.join(", ")
+            );
+        }
+        out.push('}');
+        out
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parts() -> Result<()> {
+        assert!("1".parse::<Parts>()?.includes(1));
+        assert!(!"1".parse::<Parts>()?.includes(2));
+        assert!("both".parse::<Parts>()?.includes(2));
+        assert!("3".parse::<Parts>().is_err());
+
+        let answers = Answers {
+            part1: None,
+            part2: Some("42".to_string()),
+        };
+        assert_eq!(answers.parts().collect::<Vec<_>>(), [(2, "42")]);
+
+        let run = Run {
+            day: 5,
+            source: "stdin".to_string(),
+            answers,
+            total: Duration::from_millis(2),
+            timer: Timer::default(),
+        };
+        assert_eq!(
+            run.to_json(false),
+            r#"{"day": 5, "input": "stdin", "part1": null, "part2": "42"}"#
+        );
+        assert_eq!(
+            run.to_json(true),
+            r#"{"day": 5, "input": "stdin", "part1": null, "part2": "42", "time_ms": 2, "phases": {}}"#
+        );
+        Ok(())
+    }
+}
@@ -0,0 +1,175 @@
+//! Rendering of puzzle state for `aoc --visualize`: a grid of characters with some of them
+//! coloured, shown on the terminal with ANSI colours or written as frames to a directory.
+
+use std::fmt;
+use std::fs;
+use std::io::{self, Write};
+use std::path::PathBuf;
+
+use anyhow::{Context, Result};
+
+use crate::grid::Grid;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Color {
+    Red,
+    Green,
+    Yellow,
+    Blue,
+    Magenta,
+    Cyan,
+    Dim,
+}
+
+impl Color {
+    fn ansi(self) -> &'static str {
+        match self {
+            Color::Red => "\x1b[1;31m",
+            Color::Green => "\x1b[1;32m",
+            Color::Yellow => "\x1b[1;33m",
+            Color::Blue => "\x1b[1;34m",
+            Color::Magenta => "\x1b[1;35m",
+            Color::Cyan => "\x1b[1;36m",
+            Color::Dim => "\x1b[2m",
+        }
+    }
+}
+
+const RESET: &str = "\x1b[0m";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Cell {
+    pub c: char,
+    pub color: Option<Color>,
+}
+
+/// A titled snapshot of a grid, with optionally coloured cells.
+#[derive(Debug, Clone)]
+pub struct Frame {
+    pub title: String,
+    pub cells: Grid<Cell>,
+}
+
+impl Frame {
+    pub fn new(title: impl Into<String>, grid: &Grid<char>) -> Self {
+        Self {
+            title: title.into(),
+            cells: grid.map(|&c| Cell { c, color: None }),
+        }
+    }
+
+    pub fn paint(&mut self, pos: (usize, usize), color: Color) {
+        self.cells[pos].color = Some(color);
+    }
+
+    /// Replaces the character at `pos`, keeping its colour.
+    pub fn set(&mut self, pos: (usize, usize), c: char) {
+        self.cells[pos].c = c;
+    }
+
+    /// Paints every cell that has no colour yet.
+    pub fn paint_rest(&mut self, color: Color) {
+        for pos in self.cells.positions() {
+            self.cells[pos].color.get_or_insert(color);
+        }
+    }
+
+    /// The frame without colours.
+    pub fn plain(&self) -> String {
+        let chars = self.cells.map(|cell| cell.c);
+        format!("{}\n{chars}\n", self.title)
+    }
+}
+
+/// Renders with ANSI colours.
+impl fmt::Display for Frame {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "{}", self.title)?;
+        for row in self.cells.rows() {
+            let mut color = None;
+            for cell in row {
+                if cell.color != color {
+                    if color.is_some() {
+                        f.write_str(RESET)?;
+                    }
+                    if let Some(c) = cell.color {
+                        f.write_str(c.ansi())?;
+                    }
+                    color = cell.color;
+                }
+                write!(f, "{}", cell.c)?;
+            }
+            if color.is_some() {
+                f.write_str(RESET)?;
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
+
+/// Where frames go.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Target {
+    Terminal,
+    /// One `dayNN-IIII.ans` file per frame, with ANSI colours so `cat` shows them.
+    Dir(PathBuf),
+}
+
+#[derive(Debug)]
+pub struct Visualizer {
+    target: Target,
+    day: u8,
+    frames: usize,
+}
+
+impl Visualizer {
+    pub fn new(target: Target, day: u8) -> Self {
+        Self {
+            target,
+            day,
+            frames: 0,
+        }
+    }
+
+    pub fn show(&mut self, frame: &Frame) -> Result<()> {
+        match &self.target {
+            Target::Terminal => writeln!(io::stdout().lock(), "{frame}")?,
+            Target::Dir(dir) => {
+                fs::create_dir_all(dir)
+                    .with_context(|| format!("Unable to create {}", dir.display()))?;
+                let path = dir.join(format!("day{:02}-{:04}.ans", self.day, self.frames));
+                fs::write(&path, frame.to_string())
+                    .with_context(|| format!("Unable to write {}", path.display()))?;
+            }
+        }
+        self.frames += 1;
+        Ok(())
+    }
+
+    /// The number of frames shown so far.
+    pub fn frames(&self) -> usize {
+        self.frames
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_frame() -> Result<()> {
+        let grid: Grid<char> = "ab\ncd".parse()?;
+        let mut frame = Frame::new("Test", &grid);
+        frame.paint((1, 0), Color::Red);
+        frame.set((0, 1), '#');
+
+        assert_eq!(frame.plain(), "Test\nab\n#d\n");
+        assert_eq!(frame.to_string(), "Test\na\x1b[1;31mb\x1b[0m\n#d\n");
+
+        frame.paint_rest(Color::Dim);
+        assert_eq!(frame.cells[(1, 0)].color, Some(Color::Red));
+        assert_eq!(frame.cells[(0, 1)].color, Some(Color::Dim));
+        Ok(())
+    }
+}